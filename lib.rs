@@ -3,15 +3,35 @@
 //! If you want this crate to use SIMD, activate the `simd` feature;
 //! You will need a nightly toolchain for this to work, however.
 //!
-//! If the feature is disabled, a sequential implementation is also provided.
+//! If you are stuck on stable, activate the `wide` feature instead: it picks
+//! an architecture-specific vector backend (SSE2 on x86/x86_64, `simd128` on
+//! wasm32) at compile time, falling back to scalar code where none applies.
+//!
+//! If neither feature is enabled, a plain sequential implementation is used.
+//!
+//! All three backends expose the same `EightPixels`, `AlphaConfig`,
+//! `BlendMode`, `blend8`, `ssaa8` and `SsaaCoords` API, so switching between
+//! them never requires touching call sites that use those items.
+//!
+//! With the `simd` feature, the pixel pack width is a const generic `N`
+//! (`PixelPack<N>`, backed by `Simd<u16, {N * 4}>`): wider packs let one
+//! register carry more pixels on hardware with wide SIMD (e.g. 16 on
+//! AVX-512). `EightPixels`, `blend8` and `ssaa8` remain thin `N = 8` aliases.
+//!
+//! The `precise` feature switches compositing and SSAA to a gamma-correct
+//! path that composites in linear light; it depends on `libm` for the
+//! sRGB transfer function's non-integer exponent, since `powf` isn't part
+//! of `core` and this crate is `#![no_std]`.
 
 #![no_std]
-#![cfg_attr(feature = "simd", feature(portable_simd))]
+#![cfg_attr(feature = "simd", feature(portable_simd, generic_const_exprs))]
+#![cfg_attr(feature = "simd", allow(incomplete_features))]
 
 use rgb::RGBA8;
 
 #[cfg_attr(feature = "simd", path = "simd.rs")]
-#[cfg_attr(not(feature = "simd"), path = "sequential.rs")]
+#[cfg_attr(all(not(feature = "simd"), feature = "wide"), path = "wide.rs")]
+#[cfg_attr(all(not(feature = "simd"), not(feature = "wide")), path = "sequential.rs")]
 mod implementation;
 
 #[doc(inline)]
@@ -1,3 +1,4 @@
+#[cfg(not(feature = "precise"))]
 use rgb::{RGBA, FromSlice};
 use super::PixelArray;
 
@@ -7,16 +8,73 @@ use super::PixelArray;
 pub struct EightPixels([u16; 32]);
 
 impl EightPixels {
-    /// Read up to 8 pixels from a byte slice (4 bytes per pixel)
-    pub fn new(src: &[u8]) -> Self {
-        let mut array = [0; 32];
-        array[..src.len()].copy_from_slice(src);
-        Self(array.map(|item| item as u16))
+    /// Read up to 8 pixels from a byte slice, `BPP` bytes per pixel, unpacking
+    /// into the canonical 4-channel RGBA16 working form
+    pub fn new<const BPP: usize>(src: &[u8]) -> Self {
+        if BPP == 4 {
+            let mut array = [0; 32];
+            array[..src.len()].copy_from_slice(src);
+            return Self(array.map(|item| item as u16));
+        }
+
+        let mut lanes = [0u16; 32];
+        for (p, bytes) in src.chunks_exact(BPP).take(8).enumerate() {
+            lanes[p * 4..][..4].copy_from_slice(&unpack::<BPP>(bytes));
+        }
+        Self(lanes)
+    }
+
+    /// Write up to 8 pixels to a byte slice, `BPP` bytes per pixel, repacking
+    /// from the canonical 4-channel RGBA16 working form
+    pub fn write<const BPP: usize>(&self, dst: &mut [u8]) {
+        if BPP == 4 {
+            dst.copy_from_slice(&self.0.map(|item| item as u8)[..dst.len()]);
+            return;
+        }
+
+        for (p, bytes) in dst.chunks_exact_mut(BPP).take(8).enumerate() {
+            let rgba = [self.0[p * 4], self.0[p * 4 + 1], self.0[p * 4 + 2], self.0[p * 4 + 3]];
+            bytes.copy_from_slice(&pack::<BPP>(rgba)[..BPP]);
+        }
     }
 
-    /// Write up to 8 pixels to a byte slice (4 bytes per pixel)
-    pub fn write(&self, dst: &mut [u8]) {
-        dst.copy_from_slice(&self.0.map(|item| item as u8)[..dst.len()]);
+    /// Multiply color channels by their pixel's alpha (`Cx' = Cx·α/255`);
+    /// the alpha channel itself passes through unmodified
+    pub fn premultiply(&self, alpha_config: AlphaConfig) -> Self {
+        match alpha_config.channel() {
+            Some(channel) => {
+                let mut result = self.0;
+                for (i, item) in result.iter_mut().enumerate() {
+                    if i & 3 == channel {
+                        continue;
+                    }
+                    let alpha = self.0[(i & !3) + channel];
+                    *item = div255(*item * alpha);
+                }
+                Self(result)
+            }
+            None => *self,
+        }
+    }
+
+    /// Undo premultiplication (`Cx = min(255, Cx'·255/α)`); the alpha
+    /// channel itself passes through unmodified
+    pub fn unpremultiply(&self, alpha_config: AlphaConfig) -> Self {
+        match alpha_config.channel() {
+            Some(channel) => {
+                let full = u8::MAX as u16;
+                let mut result = self.0;
+                for (i, item) in result.iter_mut().enumerate() {
+                    if i & 3 == channel {
+                        continue;
+                    }
+                    let alpha = self.0[(i & !3) + channel].max(1);
+                    *item = (*item * full / alpha).min(full);
+                }
+                Self(result)
+            }
+            None => *self,
+        }
     }
 }
 
@@ -28,35 +86,398 @@ pub enum AlphaConfig {
     SecondByte,
     ThirdByte,
     FourthByte,
+    /// Like `FirstByte`, but source and destination color channels are
+    /// already premultiplied by their pixel's alpha
+    PremultipliedFirstByte,
+    /// Like `SecondByte`, but source and destination color channels are
+    /// already premultiplied by their pixel's alpha
+    PremultipliedSecondByte,
+    /// Like `ThirdByte`, but source and destination color channels are
+    /// already premultiplied by their pixel's alpha
+    PremultipliedThirdByte,
+    /// Like `FourthByte`, but source and destination color channels are
+    /// already premultiplied by their pixel's alpha
+    PremultipliedFourthByte,
     /// The pixels will be directly copied, no blending
     None,
 }
 
-/// Perform alpha compositing on up to eight pixels
+impl AlphaConfig {
+    /// Byte offset of the alpha channel within a pixel, if any
+    fn channel(self) -> Option<usize> {
+        match self {
+            AlphaConfig::FirstByte  | AlphaConfig::PremultipliedFirstByte  => Some(0),
+            AlphaConfig::SecondByte | AlphaConfig::PremultipliedSecondByte => Some(1),
+            AlphaConfig::ThirdByte  | AlphaConfig::PremultipliedThirdByte  => Some(2),
+            AlphaConfig::FourthByte | AlphaConfig::PremultipliedFourthByte => Some(3),
+            AlphaConfig::None => None,
+        }
+    }
+
+    /// Whether color channels are already premultiplied by alpha
+    fn is_premultiplied(self) -> bool {
+        matches!(
+            self,
+            AlphaConfig::PremultipliedFirstByte
+                | AlphaConfig::PremultipliedSecondByte
+                | AlphaConfig::PremultipliedThirdByte
+                | AlphaConfig::PremultipliedFourthByte
+        )
+    }
+}
+
+/// Correctly-rounded `round(x / 255)`, without an actual division
+#[inline(always)]
+fn div255(x: u16) -> u16 {
+    let t = x + 128;
+    ((t >> 8) + t) >> 8
+}
+
+/// sRGB (0..=255) to linear light, used by the `precise` feature's gamma-correct path
+#[cfg(feature = "precise")]
+fn srgb_to_linear(c: u16) -> f32 {
+    let c = c as f32 / 255.0;
+    // `f32::powf` isn't available in `core`; this crate is `#![no_std]`
+    if c <= 0.04045 { c / 12.92 } else { libm::powf((c + 0.055) / 1.055, 2.4) }
+}
+
+/// Linear light to sRGB (0..=255)
+#[cfg(feature = "precise")]
+fn linear_to_srgb(c: f32) -> u16 {
+    let c = c.clamp(0.0, 1.0);
+    let encoded = if c <= 0.0031308 { c * 12.92 } else { 1.055 * libm::powf(c, 1.0 / 2.4) - 0.055 };
+    (encoded * 255.0 + 0.5) as u16
+}
+
+/// Decode one canonical channel to a linearized `0.0..=1.0` float; alpha is
+/// already linear and is only rescaled, never gamma-decoded
+#[cfg(feature = "precise")]
+fn decode_channel(value: u16, is_alpha: bool) -> f32 {
+    match is_alpha {
+        true => value as f32 / 255.0,
+        false => srgb_to_linear(value),
+    }
+}
+
+/// Encode one linearized `0.0..=1.0` float back to a canonical channel
+#[cfg(feature = "precise")]
+fn encode_channel(value: f32, is_alpha: bool) -> u16 {
+    match is_alpha {
+        true => (value.clamp(0.0, 1.0) * 255.0 + 0.5) as u16,
+        false => linear_to_srgb(value),
+    }
+}
+
+/// Unpack one pixel (`BPP` bytes) into canonical `[r, g, b, a]` 16-bit channels.
+/// Formats without an alpha channel (3, 2 and 6 bytes per pixel) report full opacity.
+fn unpack<const BPP: usize>(bytes: &[u8]) -> [u16; 4] {
+    match BPP {
+        // 24bpp RGB
+        3 => [bytes[0] as u16, bytes[1] as u16, bytes[2] as u16, u8::MAX as u16],
+        // RGB565
+        2 => {
+            let packed = u16::from_le_bytes([bytes[0], bytes[1]]);
+            let r5 = (packed >> 11) & 0x1F;
+            let g6 = (packed >> 5) & 0x3F;
+            let b5 = packed & 0x1F;
+            [(r5 << 3) | (r5 >> 2), (g6 << 2) | (g6 >> 4), (b5 << 3) | (b5 >> 2), u8::MAX as u16]
+        }
+        // 16-bit-per-channel RGB, downscaled to 8-bit
+        6 => [
+            u16::from_le_bytes([bytes[0], bytes[1]]) >> 8,
+            u16::from_le_bytes([bytes[2], bytes[3]]) >> 8,
+            u16::from_le_bytes([bytes[4], bytes[5]]) >> 8,
+            u8::MAX as u16,
+        ],
+        // 16-bit-per-channel RGBA, downscaled to 8-bit
+        8 => [
+            u16::from_le_bytes([bytes[0], bytes[1]]) >> 8,
+            u16::from_le_bytes([bytes[2], bytes[3]]) >> 8,
+            u16::from_le_bytes([bytes[4], bytes[5]]) >> 8,
+            u16::from_le_bytes([bytes[6], bytes[7]]) >> 8,
+        ],
+        // 8-bit RGBA (the `BPP == 4` fast path bypasses this function)
+        _ => [bytes[0] as u16, bytes[1] as u16, bytes[2] as u16, bytes[3] as u16],
+    }
+}
+
+/// Repack canonical `[r, g, b, a]` 16-bit channels into one pixel (`BPP` bytes)
+fn pack<const BPP: usize>(rgba: [u16; 4]) -> [u8; 8] {
+    match BPP {
+        3 => [rgba[0] as u8, rgba[1] as u8, rgba[2] as u8, 0, 0, 0, 0, 0],
+        2 => {
+            let r5 = (rgba[0] >> 3) & 0x1F;
+            let g6 = (rgba[1] >> 2) & 0x3F;
+            let b5 = (rgba[2] >> 3) & 0x1F;
+            let packed = ((r5 << 11) | (g6 << 5) | b5).to_le_bytes();
+            [packed[0], packed[1], 0, 0, 0, 0, 0, 0]
+        }
+        6 => {
+            let [r0, r1] = widen(rgba[0]);
+            let [g0, g1] = widen(rgba[1]);
+            let [b0, b1] = widen(rgba[2]);
+            [r0, r1, g0, g1, b0, b1, 0, 0]
+        }
+        8 => {
+            let [r0, r1] = widen(rgba[0]);
+            let [g0, g1] = widen(rgba[1]);
+            let [b0, b1] = widen(rgba[2]);
+            let [a0, a1] = widen(rgba[3]);
+            [r0, r1, g0, g1, b0, b1, a0, a1]
+        }
+        _ => [rgba[0] as u8, rgba[1] as u8, rgba[2] as u8, rgba[3] as u8, 0, 0, 0, 0],
+    }
+}
+
+/// Replicate an 8-bit channel value into a 16-bit-per-channel byte pair (e.g. `0xab` -> `0xabab`)
+fn widen(channel: u16) -> [u8; 2] {
+    ((channel << 8) | channel).to_le_bytes()
+}
+
+/// Porter-Duff operators and separable blend modes, composited onto the
+/// destination pixel in place of plain source-over copy/blend
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BlendMode {
+    Clear,
+    Src,
+    Dst,
+    SrcOver,
+    DstOver,
+    SrcIn,
+    DstIn,
+    SrcOut,
+    DstOut,
+    SrcAtop,
+    DstAtop,
+    Xor,
+    Add,
+    Multiply,
+    Screen,
+    Overlay,
+    Darken,
+    Lighten,
+    ColorDodge,
+    ColorBurn,
+    HardLight,
+    Difference,
+}
+
+/// Per-channel `(Fa, Fb)` coefficients for `Cr = Fa·Cs + Fb·Cd`, scaled to `0..=255`
+#[inline(always)]
+fn porter_duff_coefficients(mode: BlendMode, src_a: u16, dst_a: u16) -> (u16, u16) {
+    let full = u8::MAX as u16;
+    let inv_src_a = full - src_a;
+    let inv_dst_a = full - dst_a;
+
+    match mode {
+        BlendMode::Clear   => (0, 0),
+        BlendMode::Src     => (full, 0),
+        BlendMode::Dst     => (0, full),
+        BlendMode::SrcOver => (full, inv_src_a),
+        BlendMode::DstOver => (inv_dst_a, full),
+        BlendMode::SrcIn   => (dst_a, 0),
+        BlendMode::DstIn   => (0, src_a),
+        BlendMode::SrcOut  => (inv_dst_a, 0),
+        BlendMode::DstOut  => (0, inv_src_a),
+        BlendMode::SrcAtop => (dst_a, inv_src_a),
+        BlendMode::DstAtop => (inv_dst_a, src_a),
+        BlendMode::Xor     => (inv_dst_a, inv_src_a),
+        BlendMode::Add     => (full, full),
+        // separable blend modes replace the source channel with the
+        // blended channel (see `separable_blend`) and composite like `SrcOver`
+        _ => (full, inv_src_a),
+    }
+}
+
+/// Per-channel separable blend function `B(Cs, Cd)`, fed into the over equation afterwards
+#[inline(always)]
+fn separable_blend(mode: BlendMode, src: u16, dst: u16) -> u16 {
+    let full = u8::MAX as u16;
+
+    match mode {
+        BlendMode::Multiply => div255(src * dst),
+        BlendMode::Screen => src + dst - div255(src * dst),
+        BlendMode::Darken => src.min(dst),
+        BlendMode::Lighten => src.max(dst),
+        BlendMode::Difference => src.max(dst) - src.min(dst),
+        // hard light swaps which operand drives the branch, compared to overlay
+        BlendMode::Overlay => hard_light(dst, src),
+        BlendMode::HardLight => hard_light(src, dst),
+        BlendMode::ColorDodge => match src {
+            _ if src >= full => full,
+            _ => (dst * full / (full - src)).min(full),
+        },
+        BlendMode::ColorBurn => match src {
+            0 => 0,
+            _ => full - ((full - dst) * full / src).min(full),
+        },
+        _ => src,
+    }
+}
+
+#[inline(always)]
+fn hard_light(src: u16, dst: u16) -> u16 {
+    let full = u8::MAX as u16;
+    if src < 128 {
+        div255(src * dst * 2)
+    } else {
+        full - div255((full - src) * (full - dst) * 2)
+    }
+}
+
+/// Perform alpha compositing on up to eight pixels using the given blend mode.
+/// `BPP` is the byte stride of `dst` (and of the bytes `src` was built from).
+///
+/// If `alpha_config` is one of the `Premultiplied*` variants, both `src` and
+/// the current content of `dst` are expected to hold color channels already
+/// multiplied by their pixel's alpha (see [`EightPixels::premultiply`]).
+///
+/// This is the fast integer path; activate the `precise` feature for a
+/// gamma-correct variant that composites in linear light.
+#[cfg(not(feature = "precise"))]
 #[inline(always)]
-pub fn blend8(
+pub fn blend8<const BPP: usize>(
     src: EightPixels,
     dst: &mut [u8],
     alpha_config: AlphaConfig,
+    mode: BlendMode,
 ) {
-    let result = if alpha_config != AlphaConfig::None {
-        let dst_p = EightPixels::new(dst);
-
-        let alpha_channel = match alpha_config {
-            AlphaConfig::FirstByte  => 0,
-            AlphaConfig::SecondByte => 1,
-            AlphaConfig::ThirdByte  => 2,
-            AlphaConfig::FourthByte => 3,
-            _ => unreachable!(),
-        };
+    let result = if let Some(channel) = alpha_config.channel() {
+        let dst_p = EightPixels::new::<BPP>(dst);
+        let premultiplied = alpha_config.is_premultiplied();
+
+        let is_separable = matches!(
+            mode,
+            BlendMode::Multiply | BlendMode::Screen | BlendMode::Overlay
+                | BlendMode::Darken | BlendMode::Lighten | BlendMode::ColorDodge
+                | BlendMode::ColorBurn | BlendMode::HardLight | BlendMode::Difference
+        );
+
+        // `Add` composites as a plain saturating sum (its (Fa, Fb) coefficients
+        // are both `full`, i.e. a no-op scale): going through the usual
+        // `Fa·Cs + Fb·Cd` multiply would overflow a `u16` for two bright pixels.
+        let is_add = matches!(mode, BlendMode::Add);
+
         let u8_max = u8::MAX as u16;
+        let mut result = [0; 32];
+        for i in 0..32 {
+            let p = i & !3;
+            let is_alpha = i - p == channel;
+            let src_a = src.0[p + channel];
+            let dst_a = dst_p.0[p + channel];
+
+            let (src_m, dst_m) = match premultiplied || is_alpha {
+                true => (src.0[i], dst_p.0[i]),
+                false => (div255(src.0[i] * src_a), div255(dst_p.0[i] * dst_a)),
+            };
+
+            // Separable blend functions operate on color only; the alpha
+            // channel always goes through the plain over equation below.
+            let effective_src = match is_separable && !is_alpha {
+                true => separable_blend(mode, src_m, dst_m),
+                false => src_m,
+            };
+
+            result[i] = if is_add {
+                (effective_src + dst_m).min(u8_max)
+            } else {
+                let (fa, fb) = porter_duff_coefficients(mode, src_a, dst_a);
+                div255((effective_src * fa) + (dst_m * fb)).min(u8_max)
+            };
+        }
+
+        // Porter-Duff math is only valid on premultiplied channels; when the
+        // caller passed a straight `AlphaConfig`, undo the premultiply we
+        // applied above before handing the result back.
+        if !premultiplied {
+            for p in (0..32).step_by(4) {
+                let alpha_r = result[p + channel].max(1);
+                for c in 0..4 {
+                    if c != channel {
+                        result[p + c] = (result[p + c] * u8_max / alpha_r).min(u8_max);
+                    }
+                }
+            }
+        }
+
+        EightPixels(result)
+    } else {
+        src
+    };
+
+    result.write::<BPP>(dst);
+}
+
+/// Perform alpha compositing on up to eight pixels using the given blend mode,
+/// performing the over equation in linearized light (gamma-correct).
+/// `BPP` is the byte stride of `dst` (and of the bytes `src` was built from).
+///
+/// Blend-mode-specific functions (`Multiply`, `Screen`, ...) still operate on
+/// gamma-encoded values, matching the CSS/SVG compositing spec; only the
+/// final `Fa·Cs + Fb·Cd` combination happens in linear light.
+///
+/// If `alpha_config` is one of the `Premultiplied*` variants, both `src` and
+/// the current content of `dst` are expected to hold color channels already
+/// multiplied by their pixel's alpha (see [`EightPixels::premultiply`]).
+#[cfg(feature = "precise")]
+#[inline(always)]
+pub fn blend8<const BPP: usize>(
+    src: EightPixels,
+    dst: &mut [u8],
+    alpha_config: AlphaConfig,
+    mode: BlendMode,
+) {
+    let result = if let Some(channel) = alpha_config.channel() {
+        let dst_p = EightPixels::new::<BPP>(dst);
+        let premultiplied = alpha_config.is_premultiplied();
+
+        let is_separable = matches!(
+            mode,
+            BlendMode::Multiply | BlendMode::Screen | BlendMode::Overlay
+                | BlendMode::Darken | BlendMode::Lighten | BlendMode::ColorDodge
+                | BlendMode::ColorBurn | BlendMode::HardLight | BlendMode::Difference
+        );
 
         let mut result = [0; 32];
         for i in 0..32 {
             let p = i & !3;
-            let src_a = src.0[p + alpha_channel];
-            let dst_a = u8_max - src_a;
-            result[i] = ((src.0[i] * src_a) + (dst_p.0[i] * dst_a)) / u8_max;
+            let is_alpha = i - p == channel;
+            let src_a = src.0[p + channel];
+            let dst_a = dst_p.0[p + channel];
+
+            let (src_m, dst_m) = match premultiplied || is_alpha {
+                true => (src.0[i], dst_p.0[i]),
+                false => (div255(src.0[i] * src_a), div255(dst_p.0[i] * dst_a)),
+            };
+
+            // Separable blend functions operate on color only; the alpha
+            // channel always goes through the plain over equation below.
+            let effective_src = match is_separable && !is_alpha {
+                true => separable_blend(mode, src_m, dst_m),
+                false => src_m,
+            };
+
+            let (fa, fb) = porter_duff_coefficients(mode, src_a, dst_a);
+            let full = u8::MAX as f32;
+            let combined = decode_channel(effective_src, is_alpha) * (fa as f32 / full)
+                + decode_channel(dst_m, is_alpha) * (fb as f32 / full);
+
+            result[i] = encode_channel(combined, is_alpha);
+        }
+
+        // Porter-Duff math is only valid on premultiplied channels; when the
+        // caller passed a straight `AlphaConfig`, undo the premultiply we
+        // applied above before handing the result back.
+        if !premultiplied {
+            let full = u8::MAX as u16;
+            for p in (0..32).step_by(4) {
+                let alpha_r = result[p + channel].max(1);
+                for c in 0..4 {
+                    if c != channel {
+                        result[p + c] = (result[p + c] * full / alpha_r).min(full);
+                    }
+                }
+            }
         }
 
         EightPixels(result)
@@ -64,7 +485,7 @@ pub fn blend8(
         src
     };
 
-    result.write(dst);
+    result.write::<BPP>(dst);
 }
 
 /// An aligned structure storing `SSAA_SQ` (x, y) subpixel coordinates for up to eight pixels
@@ -94,7 +515,11 @@ impl<const SSAA_SQ: usize> SsaaCoords<SSAA_SQ> {
     }
 }
 
-/// Performs SSAA on up to 8 pixels
+/// Performs SSAA on up to 8 pixels.
+///
+/// This is the fast integer path; activate the `precise` feature for a
+/// gamma-correct variant that averages subpixels in linear light.
+#[cfg(not(feature = "precise"))]
 #[inline(always)]
 pub fn ssaa8<P: PixelArray, const SSAA_SQ: usize>(
     src_coords: SsaaCoords<SSAA_SQ>,
@@ -107,7 +532,7 @@ pub fn ssaa8<P: PixelArray, const SSAA_SQ: usize>(
     // SUM SUBPIXELS
 
     let mut ssaa_px = [0; 8];
-    let mut result = EightPixels::new(&[]);
+    let mut result = EightPixels::new::<4>(&[]);
 
     for i in 0..SSAA_SQ {
         for j in 0..8 {
@@ -145,3 +570,112 @@ pub fn ssaa8<P: PixelArray, const SSAA_SQ: usize>(
 
     result
 }
+
+/// Performs SSAA on up to 8 pixels, summing subpixels in linearized light
+/// (sRGB->linear on read, linear->sRGB on write) instead of 8-bit integer
+/// space. This avoids both the darkened edges that 8-bit SSAA produces and
+/// the accumulator overflow that a large `SSAA_SQ` could cause in `u16`.
+#[cfg(feature = "precise")]
+#[inline(always)]
+pub fn ssaa8<P: PixelArray, const SSAA_SQ: usize>(
+    src_coords: SsaaCoords<SSAA_SQ>,
+    src: &P,
+) -> EightPixels {
+    let src_w = src.width();
+    let src_h = src.height();
+    let src_l = src.length();
+
+    // SUM SUBPIXELS
+
+    let mut sums = [0f32; 32];
+
+    for i in 0..SSAA_SQ {
+        for j in 0..8 {
+            let src_o = src_coords.src_o[i][j];
+            let src_x = src_coords.src_x[i][j];
+            let src_y = src_coords.src_y[i][j];
+            let src_i = src_y * src_w + src_x;
+
+            let usable_x = src_x < src_w;
+            let usable_y = src_y < src_h;
+            let usable_l = src_i < src_l;
+            let usable = usable_x & usable_y & usable_l;
+
+            if usable {
+                let rgba: [u8; 4] = src.get(src_i).into();
+                for c in 0..4 {
+                    sums[src_o * 4 + c] += decode_channel(rgba[c] as u16, c == 3);
+                }
+            }
+        }
+    }
+
+    // AVERAGE AND RE-ENCODE
+
+    let mut result = [0u16; 32];
+    for i in 0..8 {
+        for c in 0..4 {
+            let average = sums[i * 4 + c] / SSAA_SQ as f32;
+            result[i * 4 + c] = encode_channel(average, c == 3);
+        }
+    }
+
+    EightPixels(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pixel(rgba: [u8; 4]) -> EightPixels {
+        let mut bytes = [0u8; 32];
+        bytes[..4].copy_from_slice(&rgba);
+        EightPixels::new::<4>(&bytes)
+    }
+
+    fn first_pixel(pack: EightPixels) -> [u8; 4] {
+        let mut bytes = [0u8; 32];
+        pack.write::<4>(&mut bytes);
+        [bytes[0], bytes[1], bytes[2], bytes[3]]
+    }
+
+    #[test]
+    fn premultiply_unpremultiply_round_trip() {
+        let straight = pixel([200, 200, 200, 128]);
+        let premultiplied = first_pixel(straight.premultiply(AlphaConfig::FourthByte));
+        // alpha passes through untouched; color channels are scaled down
+        assert_eq!(premultiplied, [100, 100, 100, 128]);
+
+        let round_tripped = first_pixel(
+            straight.premultiply(AlphaConfig::FourthByte).unpremultiply(AlphaConfig::FourthByte),
+        );
+        // div255/unpremultiply round-trips within a rounding step, not bit-exact
+        assert_eq!(round_tripped, [199, 199, 199, 128]);
+    }
+
+    #[test]
+    fn src_over_straight_alpha() {
+        let src = pixel([255, 0, 0, 200]);
+        let mut dst = [0u8; 32];
+        dst[..4].copy_from_slice(&[0, 0, 255, 100]);
+
+        blend8::<4>(src, &mut dst, AlphaConfig::FourthByte, BlendMode::SrcOver);
+
+        // over equation: Ar = Aa + Ad·(1-Aa)/255 = 200 + 100·55/255 ≈ 222
+        assert_eq!(dst[3], 222);
+        assert_eq!(&dst[..4], &[229, 0, 25, 222]);
+    }
+
+    #[test]
+    fn separable_mode_leaves_alpha_on_plain_over_equation() {
+        let src = pixel([255, 0, 0, 200]);
+        let mut dst = [0u8; 32];
+        dst[..4].copy_from_slice(&[0, 0, 255, 100]);
+
+        blend8::<4>(src, &mut dst, AlphaConfig::FourthByte, BlendMode::Multiply);
+
+        // `Multiply` blends color through B(Cs,Cd), but alpha must match the
+        // same plain over-equation result as `SrcOver`, not the blend function
+        assert_eq!(dst[3], 222);
+    }
+}
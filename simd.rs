@@ -1,46 +1,250 @@
 use super::PixelArray;
 
 use core::simd::{
+    LaneCount,
+    Mask,
+    Simd,
+    SupportedLaneCount,
+    Swizzle,
+    SimdOrd,
+    SimdPartialEq,
     SimdPartialOrd,
     SimdUint,
-    simd_swizzle,
-    usizex8,
     u8x4,
-    u16x4,
-    u8x32,
-    u16x32,
 };
 
-/// A pack of 8 pixels (SIMD alignment)
+/// A pack of `N` pixels (SIMD alignment), four 16-bit channels each.
+///
+/// `N` used to be hardwired to 8; now that `portable_simd` lets `Simd`
+/// carry arbitrary lane counts, wider packs (16 on AVX-512, say) let one
+/// register carry more pixels per iteration, while narrower targets (wasm)
+/// can pick a pack that actually fits their native vector width.
 #[derive(Copy, Clone, Debug)]
 #[repr(transparent)]
-pub struct EightPixels(u16x32);
+pub struct PixelPack<const N: usize>(Simd<u16, { N * 4 }>)
+where
+    LaneCount<{ N * 4 }>: SupportedLaneCount;
 
-impl EightPixels {
-    /// Read up to 8 pixels from a byte slice (4 bytes per pixel)
-    pub fn new(src: &[u8]) -> Self {
-        let mut array = [0; 32];
-        array[..src.len()].copy_from_slice(src);
-        Self(u8x32::from_array(array).cast())
+/// A pack of 8 pixels (SIMD alignment); kept as a thin alias for source compatibility
+pub type EightPixels = PixelPack<8>;
+
+impl<const N: usize> PixelPack<N>
+where
+    LaneCount<{ N * 4 }>: SupportedLaneCount,
+{
+    /// Read up to `N` pixels from a byte slice, `BPP` bytes per pixel, unpacking
+    /// into the canonical 4-channel RGBA16 working form
+    pub fn new<const BPP: usize>(src: &[u8]) -> Self {
+        if BPP == 4 {
+            let mut array = [0; N * 4];
+            array[..src.len()].copy_from_slice(src);
+            return Self(Simd::<u8, { N * 4 }>::from_array(array).cast());
+        }
+
+        let mut lanes = [0u16; N * 4];
+        for (p, bytes) in src.chunks_exact(BPP).take(N).enumerate() {
+            lanes[p * 4..][..4].copy_from_slice(&unpack::<BPP>(bytes));
+        }
+        Self(Simd::from_array(lanes))
     }
 
-    /// Write up to 8 pixels to a byte slice (4 bytes per pixel)
-    pub fn write(&self, dst: &mut [u8]) {
-        let u8simd: u8x32 = self.0.cast();
-        dst.copy_from_slice(&u8simd.as_array()[..dst.len()]);
+    /// Write up to `N` pixels to a byte slice, `BPP` bytes per pixel, repacking
+    /// from the canonical 4-channel RGBA16 working form
+    pub fn write<const BPP: usize>(&self, dst: &mut [u8]) {
+        if BPP == 4 {
+            let u8simd: Simd<u8, { N * 4 }> = self.0.cast();
+            dst.copy_from_slice(&u8simd.as_array()[..dst.len()]);
+            return;
+        }
+
+        let lanes = self.0.to_array();
+        for (p, bytes) in dst.chunks_exact_mut(BPP).take(N).enumerate() {
+            let rgba = [lanes[p * 4], lanes[p * 4 + 1], lanes[p * 4 + 2], lanes[p * 4 + 3]];
+            bytes.copy_from_slice(&pack::<BPP>(rgba)[..BPP]);
+        }
+    }
+
+    /// Multiply color channels by their pixel's alpha (`Cx' = Cx·α/255`);
+    /// the alpha channel itself passes through unmodified
+    pub fn premultiply(&self, alpha_config: AlphaConfig) -> Self {
+        match alpha_config.channel() {
+            Some(channel) => {
+                let alpha = gather_alpha(self.0, channel);
+                Self(premultiply_colors(self.0, alpha, channel))
+            }
+            None => *self,
+        }
+    }
+
+    /// Undo premultiplication (`Cx = min(255, Cx'·255/α)`); the alpha
+    /// channel itself passes through unmodified
+    pub fn unpremultiply(&self, alpha_config: AlphaConfig) -> Self {
+        match alpha_config.channel() {
+            Some(channel) => Self(unpremultiply_colors(self.0, channel)),
+            None => *self,
+        }
+    }
+}
+
+/// `simd_swizzle!`'s generated `Swizzle` impl is a nested item, so it can't
+/// close over an outer function's const generic; implementing `Swizzle`
+/// by hand, generic over both `LANES` and `CHANNEL` itself, sidesteps that.
+struct AlphaSwizzle<const LANES: usize, const CHANNEL: usize>;
+
+impl<const LANES: usize, const CHANNEL: usize> Swizzle<LANES> for AlphaSwizzle<LANES, CHANNEL> {
+    const INDEX: [usize; LANES] = alpha_swizzle::<LANES>(CHANNEL);
+}
+
+/// Broadcast each pixel's byte at `channel` (0..=3) across that pixel's 4 lanes,
+/// e.g. for a 2-pixel pack and `channel == 3`: `[a0,a0,a0,a0, a1,a1,a1,a1]`
+///
+/// `channel` only ever takes one of 4 values at runtime, so this resolves to
+/// one of 4 compile-time-fixed vector shuffles instead of a store/gather/load
+/// round trip through memory.
+#[inline(always)]
+fn gather_alpha<const LANES: usize>(pack: Simd<u16, LANES>, channel: usize) -> Simd<u16, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    match channel {
+        0 => AlphaSwizzle::<LANES, 0>::swizzle(pack),
+        1 => AlphaSwizzle::<LANES, 1>::swizzle(pack),
+        2 => AlphaSwizzle::<LANES, 2>::swizzle(pack),
+        _ => AlphaSwizzle::<LANES, 3>::swizzle(pack),
     }
 }
 
-const fn gen_swizzle(byte: usize) -> [usize; 32] {
-    let mut result = [byte; 32];
+/// Swizzle indices broadcasting byte `channel` across every 4-lane pixel
+const fn alpha_swizzle<const LANES: usize>(channel: usize) -> [usize; LANES] {
+    let mut result = [channel; LANES];
     let mut i = 0;
-    while i < 32 {
+    while i < LANES {
         result[i] += i & !3;
         i += 1;
     }
     result
 }
 
+/// Mask that is `true` at each pixel's alpha lane (position `channel` within
+/// every 4-lane pixel) and `false` elsewhere; used to leave the alpha channel
+/// untouched while un-premultiplying color channels (see [`blend_n`]).
+#[inline(always)]
+fn alpha_lane_mask<const LANES: usize>(channel: usize) -> Mask<i16, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    match channel {
+        0 => Mask::from_array(alpha_lanes::<LANES>(0)),
+        1 => Mask::from_array(alpha_lanes::<LANES>(1)),
+        2 => Mask::from_array(alpha_lanes::<LANES>(2)),
+        _ => Mask::from_array(alpha_lanes::<LANES>(3)),
+    }
+}
+
+const fn alpha_lanes<const LANES: usize>(channel: usize) -> [bool; LANES] {
+    let mut result = [false; LANES];
+    let mut i = 0;
+    while i < LANES {
+        result[i] = (i & 3) == channel;
+        i += 1;
+    }
+    result
+}
+
+/// Multiply color lanes by `alpha`, leaving the alpha lane itself untouched
+/// (premultiplied alpha is defined as `Cx' = Cx·α/255, α' = α`; multiplying
+/// alpha by itself would corrupt it).
+#[inline(always)]
+fn premultiply_colors<const LANES: usize>(pack: Simd<u16, LANES>, alpha: Simd<u16, LANES>, channel: usize) -> Simd<u16, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let straight = div255(pack * alpha);
+    alpha_lane_mask(channel).select(pack, straight)
+}
+
+/// Divide color lanes by the freshly composited alpha, undoing the premultiply
+/// applied before blending; the alpha lane itself is left untouched. Porter-Duff
+/// math is only valid on premultiplied channels, so straight `AlphaConfig`
+/// callers need this correction before the result is written back.
+#[inline(always)]
+fn unpremultiply_colors<const LANES: usize>(combined: Simd<u16, LANES>, channel: usize) -> Simd<u16, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let full = Simd::splat(u8::MAX as u16);
+    let one = Simd::splat(1);
+    let alpha_r = gather_alpha(combined, channel).simd_max(one);
+    let straight = (combined * full / alpha_r).simd_min(full);
+    alpha_lane_mask(channel).select(combined, straight)
+}
+
+/// Unpack one pixel (`BPP` bytes) into canonical `[r, g, b, a]` 16-bit channels.
+/// Formats without an alpha channel (3, 2 and 6 bytes per pixel) report full opacity.
+fn unpack<const BPP: usize>(bytes: &[u8]) -> [u16; 4] {
+    match BPP {
+        // 24bpp RGB
+        3 => [bytes[0] as u16, bytes[1] as u16, bytes[2] as u16, u8::MAX as u16],
+        // RGB565
+        2 => {
+            let packed = u16::from_le_bytes([bytes[0], bytes[1]]);
+            let r5 = (packed >> 11) & 0x1F;
+            let g6 = (packed >> 5) & 0x3F;
+            let b5 = packed & 0x1F;
+            [(r5 << 3) | (r5 >> 2), (g6 << 2) | (g6 >> 4), (b5 << 3) | (b5 >> 2), u8::MAX as u16]
+        }
+        // 16-bit-per-channel RGB, downscaled to 8-bit
+        6 => [
+            u16::from_le_bytes([bytes[0], bytes[1]]) >> 8,
+            u16::from_le_bytes([bytes[2], bytes[3]]) >> 8,
+            u16::from_le_bytes([bytes[4], bytes[5]]) >> 8,
+            u8::MAX as u16,
+        ],
+        // 16-bit-per-channel RGBA, downscaled to 8-bit
+        8 => [
+            u16::from_le_bytes([bytes[0], bytes[1]]) >> 8,
+            u16::from_le_bytes([bytes[2], bytes[3]]) >> 8,
+            u16::from_le_bytes([bytes[4], bytes[5]]) >> 8,
+            u16::from_le_bytes([bytes[6], bytes[7]]) >> 8,
+        ],
+        // 8-bit RGBA (the `BPP == 4` fast path bypasses this function)
+        _ => [bytes[0] as u16, bytes[1] as u16, bytes[2] as u16, bytes[3] as u16],
+    }
+}
+
+/// Repack canonical `[r, g, b, a]` 16-bit channels into one pixel (`BPP` bytes)
+fn pack<const BPP: usize>(rgba: [u16; 4]) -> [u8; 8] {
+    match BPP {
+        3 => [rgba[0] as u8, rgba[1] as u8, rgba[2] as u8, 0, 0, 0, 0, 0],
+        2 => {
+            let r5 = (rgba[0] >> 3) & 0x1F;
+            let g6 = (rgba[1] >> 2) & 0x3F;
+            let b5 = (rgba[2] >> 3) & 0x1F;
+            let packed = ((r5 << 11) | (g6 << 5) | b5).to_le_bytes();
+            [packed[0], packed[1], 0, 0, 0, 0, 0, 0]
+        }
+        6 => {
+            let [r0, r1] = widen(rgba[0]);
+            let [g0, g1] = widen(rgba[1]);
+            let [b0, b1] = widen(rgba[2]);
+            [r0, r1, g0, g1, b0, b1, 0, 0]
+        }
+        8 => {
+            let [r0, r1] = widen(rgba[0]);
+            let [g0, g1] = widen(rgba[1]);
+            let [b0, b1] = widen(rgba[2]);
+            let [a0, a1] = widen(rgba[3]);
+            [r0, r1, g0, g1, b0, b1, a0, a1]
+        }
+        _ => [rgba[0] as u8, rgba[1] as u8, rgba[2] as u8, rgba[3] as u8, 0, 0, 0, 0],
+    }
+}
+
+/// Replicate an 8-bit channel value into a 16-bit-per-channel byte pair (e.g. `0xab` -> `0xabab`)
+fn widen(channel: u16) -> [u8; 2] {
+    ((channel << 8) | channel).to_le_bytes()
+}
+
 /// Supported Alpha configurations
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[repr(usize)]
@@ -49,81 +253,434 @@ pub enum AlphaConfig {
     SecondByte,
     ThirdByte,
     FourthByte,
+    /// Like `FirstByte`, but source and destination color channels are
+    /// already premultiplied by their pixel's alpha
+    PremultipliedFirstByte,
+    /// Like `SecondByte`, but source and destination color channels are
+    /// already premultiplied by their pixel's alpha
+    PremultipliedSecondByte,
+    /// Like `ThirdByte`, but source and destination color channels are
+    /// already premultiplied by their pixel's alpha
+    PremultipliedThirdByte,
+    /// Like `FourthByte`, but source and destination color channels are
+    /// already premultiplied by their pixel's alpha
+    PremultipliedFourthByte,
     /// The pixels will be directly copied, no blending
     None,
 }
 
-/// Perform alpha compositing on up to eight pixels
+impl AlphaConfig {
+    /// Byte offset of the alpha channel within a pixel, if any
+    fn channel(self) -> Option<usize> {
+        match self {
+            AlphaConfig::FirstByte  | AlphaConfig::PremultipliedFirstByte  => Some(0),
+            AlphaConfig::SecondByte | AlphaConfig::PremultipliedSecondByte => Some(1),
+            AlphaConfig::ThirdByte  | AlphaConfig::PremultipliedThirdByte  => Some(2),
+            AlphaConfig::FourthByte | AlphaConfig::PremultipliedFourthByte => Some(3),
+            AlphaConfig::None => None,
+        }
+    }
+
+    /// Whether color channels are already premultiplied by alpha
+    fn is_premultiplied(self) -> bool {
+        matches!(
+            self,
+            AlphaConfig::PremultipliedFirstByte
+                | AlphaConfig::PremultipliedSecondByte
+                | AlphaConfig::PremultipliedThirdByte
+                | AlphaConfig::PremultipliedFourthByte
+        )
+    }
+}
+
+/// Correctly-rounded `round(x / 255)`, without an actual division
 #[inline(always)]
-pub fn blend8(
-    src: EightPixels,
+fn div255<const LANES: usize>(x: Simd<u16, LANES>) -> Simd<u16, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let eight = Simd::splat(8);
+    let t = x + Simd::splat(128);
+    ((t >> eight) + t) >> eight
+}
+
+/// sRGB (0..=255) to linear light, used by the `precise` feature's gamma-correct path
+#[cfg(feature = "precise")]
+fn srgb_to_linear(c: u16) -> f32 {
+    let c = c as f32 / 255.0;
+    // `f32::powf` isn't available in `core`; this crate is `#![no_std]`
+    if c <= 0.04045 { c / 12.92 } else { libm::powf((c + 0.055) / 1.055, 2.4) }
+}
+
+/// Linear light to sRGB (0..=255)
+#[cfg(feature = "precise")]
+fn linear_to_srgb(c: f32) -> u16 {
+    let c = c.clamp(0.0, 1.0);
+    let encoded = if c <= 0.0031308 { c * 12.92 } else { 1.055 * libm::powf(c, 1.0 / 2.4) - 0.055 };
+    (encoded * 255.0 + 0.5) as u16
+}
+
+/// Decode one canonical channel to a linearized `0.0..=1.0` float; alpha is
+/// already linear and is only rescaled, never gamma-decoded
+#[cfg(feature = "precise")]
+fn decode_channel(value: u16, is_alpha: bool) -> f32 {
+    match is_alpha {
+        true => value as f32 / 255.0,
+        false => srgb_to_linear(value),
+    }
+}
+
+/// Encode one linearized `0.0..=1.0` float back to a canonical channel
+#[cfg(feature = "precise")]
+fn encode_channel(value: f32, is_alpha: bool) -> u16 {
+    match is_alpha {
+        true => (value.clamp(0.0, 1.0) * 255.0 + 0.5) as u16,
+        false => linear_to_srgb(value),
+    }
+}
+
+/// Porter-Duff operators and separable blend modes, composited onto the
+/// destination pixel in place of plain source-over copy/blend
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BlendMode {
+    Clear,
+    Src,
+    Dst,
+    SrcOver,
+    DstOver,
+    SrcIn,
+    DstIn,
+    SrcOut,
+    DstOut,
+    SrcAtop,
+    DstAtop,
+    Xor,
+    Add,
+    Multiply,
+    Screen,
+    Overlay,
+    Darken,
+    Lighten,
+    ColorDodge,
+    ColorBurn,
+    HardLight,
+    Difference,
+}
+
+/// Per-channel `(Fa, Fb)` coefficients for `Cr = Fa·Cs + Fb·Cd`, scaled to `0..=255`
+#[inline(always)]
+fn porter_duff_coefficients<const LANES: usize>(
+    mode: BlendMode,
+    src_a: Simd<u16, LANES>,
+    dst_a: Simd<u16, LANES>,
+) -> (Simd<u16, LANES>, Simd<u16, LANES>)
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let zero = Simd::splat(0);
+    let full = Simd::splat(u8::MAX as u16);
+    let inv_src_a = full - src_a;
+    let inv_dst_a = full - dst_a;
+
+    match mode {
+        BlendMode::Clear   => (zero, zero),
+        BlendMode::Src     => (full, zero),
+        BlendMode::Dst     => (zero, full),
+        BlendMode::SrcOver => (full, inv_src_a),
+        BlendMode::DstOver => (inv_dst_a, full),
+        BlendMode::SrcIn   => (dst_a, zero),
+        BlendMode::DstIn   => (zero, src_a),
+        BlendMode::SrcOut  => (inv_dst_a, zero),
+        BlendMode::DstOut  => (zero, inv_src_a),
+        BlendMode::SrcAtop => (dst_a, inv_src_a),
+        BlendMode::DstAtop => (inv_dst_a, src_a),
+        BlendMode::Xor     => (inv_dst_a, inv_src_a),
+        BlendMode::Add     => (full, full),
+        // separable blend modes replace the source channel with the
+        // blended channel (see `separable_blend`) and composite like `SrcOver`
+        _ => (full, inv_src_a),
+    }
+}
+
+/// Per-channel separable blend function `B(Cs, Cd)`, fed into the over equation afterwards
+#[inline(always)]
+fn separable_blend<const LANES: usize>(
+    mode: BlendMode,
+    src: Simd<u16, LANES>,
+    dst: Simd<u16, LANES>,
+) -> Simd<u16, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let full = Simd::splat(u8::MAX as u16);
+    let one = Simd::splat(1);
+    let zero = Simd::splat(0);
+
+    match mode {
+        BlendMode::Multiply => div255(src * dst),
+        BlendMode::Screen => src + dst - div255(src * dst),
+        BlendMode::Darken => src.simd_min(dst),
+        BlendMode::Lighten => src.simd_max(dst),
+        BlendMode::Difference => src.simd_max(dst) - src.simd_min(dst),
+        // hard light swaps which operand drives the branch, compared to overlay
+        BlendMode::Overlay => hard_light(dst, src),
+        BlendMode::HardLight => hard_light(src, dst),
+        BlendMode::ColorDodge => {
+            let denom = (full - src).simd_max(one);
+            let dodged = (dst * full / denom).simd_min(full);
+            src.simd_ge(full).select(full, dodged)
+        }
+        BlendMode::ColorBurn => {
+            let denom = src.simd_max(one);
+            let burned = full - ((full - dst) * full / denom).simd_min(full);
+            src.simd_eq(zero).select(zero, burned)
+        }
+        _ => src,
+    }
+}
+
+#[inline(always)]
+fn hard_light<const LANES: usize>(src: Simd<u16, LANES>, dst: Simd<u16, LANES>) -> Simd<u16, LANES>
+where
+    LaneCount<LANES>: SupportedLaneCount,
+{
+    let full = Simd::splat(u8::MAX as u16);
+    let two = Simd::splat(2);
+    let half = Simd::splat(128);
+
+    let lo = div255(src * dst * two);
+    let hi = full - div255((full - src) * (full - dst) * two);
+    src.simd_lt(half).select(lo, hi)
+}
+
+/// Perform alpha compositing on up to `N` pixels using the given blend mode.
+/// `BPP` is the byte stride of `dst` (and of the bytes `src` was built from).
+///
+/// If `alpha_config` is one of the `Premultiplied*` variants, both `src` and
+/// the current content of `dst` are expected to hold color channels already
+/// multiplied by their pixel's alpha (see [`PixelPack::premultiply`]).
+///
+/// This is the fast integer path; activate the `precise` feature for a
+/// gamma-correct variant that composites in linear light.
+#[cfg(not(feature = "precise"))]
+#[inline(always)]
+pub fn blend_n<const N: usize, const BPP: usize>(
+    src: PixelPack<N>,
     dst: &mut [u8],
     alpha_config: AlphaConfig,
-) {
-    let result = if alpha_config != AlphaConfig::None {
-        let dst_p = EightPixels::new(dst);
+    mode: BlendMode,
+) where
+    LaneCount<{ N * 4 }>: SupportedLaneCount,
+{
+    let result = if let Some(channel) = alpha_config.channel() {
+        let dst_p = PixelPack::<N>::new::<BPP>(dst);
+        let premultiplied = alpha_config.is_premultiplied();
 
         // map [r, g, b, a] to [a, a, a, a]
-        let src_a = match alpha_config {
-            AlphaConfig::FirstByte  => simd_swizzle!(src.0, gen_swizzle(0)),
-            AlphaConfig::SecondByte => simd_swizzle!(src.0, gen_swizzle(1)),
-            AlphaConfig::ThirdByte  => simd_swizzle!(src.0, gen_swizzle(2)),
-            AlphaConfig::FourthByte => simd_swizzle!(src.0, gen_swizzle(3)),
-            _ => unreachable!(),
+        let src_a = gather_alpha(src.0, channel);
+        let dst_a = gather_alpha(dst_p.0, channel);
+
+        let (src_m, dst_m) = if premultiplied {
+            (src.0, dst_p.0)
+        } else {
+            (premultiply_colors(src.0, src_a, channel), premultiply_colors(dst_p.0, dst_a, channel))
+        };
+
+        // Separable blend functions operate on color only; the alpha channel
+        // always goes through the plain over equation below, using `src_a`.
+        let effective_src = match mode {
+            BlendMode::Multiply | BlendMode::Screen | BlendMode::Overlay
+            | BlendMode::Darken | BlendMode::Lighten | BlendMode::ColorDodge
+            | BlendMode::ColorBurn | BlendMode::HardLight | BlendMode::Difference => {
+                alpha_lane_mask(channel).select(src_m, separable_blend(mode, src_m, dst_m))
+            }
+            _ => src_m,
         };
 
-        let u8_max = u16x32::from_array([u8::MAX as _; 32]);
-        let dst_a = u8_max - src_a;
+        let full = Simd::splat(u8::MAX as u16);
 
-        EightPixels(((src.0 * src_a) + (dst_p.0 * dst_a)) / u8_max)
+        // `Add`'s (Fa, Fb) coefficients are both `full`, i.e. a no-op scale;
+        // going through the usual multiply would overflow a `u16` for two
+        // bright pixels, so treat it as a plain saturating sum instead.
+        let combined = if matches!(mode, BlendMode::Add) {
+            effective_src + dst_m
+        } else {
+            let (fa, fb) = porter_duff_coefficients(mode, src_a, dst_a);
+            div255((effective_src * fa) + (dst_m * fb))
+        };
+        let combined = combined.simd_min(full);
+
+        // Porter-Duff math is only valid on premultiplied channels; when the
+        // caller passed a straight `AlphaConfig`, undo the premultiply we
+        // applied above before handing the result back.
+        let combined = match premultiplied {
+            true => combined,
+            false => unpremultiply_colors(combined, channel),
+        };
+
+        PixelPack(combined)
     } else {
         src
     };
 
-    result.write(dst);
+    result.write::<BPP>(dst);
+}
+
+/// Perform alpha compositing on up to `N` pixels using the given blend mode,
+/// performing the over equation in linearized light (gamma-correct).
+/// `BPP` is the byte stride of `dst` (and of the bytes `src` was built from).
+///
+/// Blend-mode-specific functions (`Multiply`, `Screen`, ...) still operate on
+/// gamma-encoded values, matching the CSS/SVG compositing spec; only the
+/// final `Fa·Cs + Fb·Cd` combination happens in linear light.
+///
+/// If `alpha_config` is one of the `Premultiplied*` variants, both `src` and
+/// the current content of `dst` are expected to hold color channels already
+/// multiplied by their pixel's alpha (see [`PixelPack::premultiply`]).
+#[cfg(feature = "precise")]
+#[inline(always)]
+pub fn blend_n<const N: usize, const BPP: usize>(
+    src: PixelPack<N>,
+    dst: &mut [u8],
+    alpha_config: AlphaConfig,
+    mode: BlendMode,
+) where
+    LaneCount<{ N * 4 }>: SupportedLaneCount,
+{
+    let result = if let Some(channel) = alpha_config.channel() {
+        let dst_p = PixelPack::<N>::new::<BPP>(dst);
+        let premultiplied = alpha_config.is_premultiplied();
+
+        let src_a = gather_alpha(src.0, channel);
+        let dst_a = gather_alpha(dst_p.0, channel);
+
+        let (src_m, dst_m) = if premultiplied {
+            (src.0, dst_p.0)
+        } else {
+            (premultiply_colors(src.0, src_a, channel), premultiply_colors(dst_p.0, dst_a, channel))
+        };
+
+        // Separable blend functions operate on color only; the alpha channel
+        // always goes through the plain over equation below, using `src_a`.
+        let effective_src = match mode {
+            BlendMode::Multiply | BlendMode::Screen | BlendMode::Overlay
+            | BlendMode::Darken | BlendMode::Lighten | BlendMode::ColorDodge
+            | BlendMode::ColorBurn | BlendMode::HardLight | BlendMode::Difference => {
+                alpha_lane_mask(channel).select(src_m, separable_blend(mode, src_m, dst_m))
+            }
+            _ => src_m,
+        };
+
+        let (fa, fb) = porter_duff_coefficients(mode, src_a, dst_a);
+
+        let src_arr = effective_src.to_array();
+        let dst_arr = dst_m.to_array();
+        let fa_arr = fa.to_array();
+        let fb_arr = fb.to_array();
+
+        let mut result = [0u16; N * 4];
+        for i in 0..N * 4 {
+            let is_alpha = (i & 3) == channel;
+            let combined = decode_channel(src_arr[i], is_alpha) * (fa_arr[i] as f32 / 255.0)
+                + decode_channel(dst_arr[i], is_alpha) * (fb_arr[i] as f32 / 255.0);
+            result[i] = encode_channel(combined, is_alpha);
+        }
+
+        // Porter-Duff math is only valid on premultiplied channels; when the
+        // caller passed a straight `AlphaConfig`, undo the premultiply we
+        // applied above before handing the result back.
+        if !premultiplied {
+            let full = u8::MAX as u16;
+            for p in (0..N * 4).step_by(4) {
+                let alpha_r = result[p + channel].max(1);
+                for c in 0..4 {
+                    if c != channel {
+                        result[p + c] = (result[p + c] * full / alpha_r).min(full);
+                    }
+                }
+            }
+        }
+
+        PixelPack(Simd::from_array(result))
+    } else {
+        src
+    };
+
+    result.write::<BPP>(dst);
+}
+
+/// Perform alpha compositing on up to eight pixels using the given blend mode; a
+/// thin `N = 8` alias over [`blend_n`] kept for source compatibility.
+/// `BPP` is the byte stride of `dst` (and of the bytes `src` was built from).
+///
+/// If `alpha_config` is one of the `Premultiplied*` variants, both `src` and
+/// the current content of `dst` are expected to hold color channels already
+/// multiplied by their pixel's alpha (see [`EightPixels::premultiply`]).
+#[inline(always)]
+pub fn blend8<const BPP: usize>(
+    src: EightPixels,
+    dst: &mut [u8],
+    alpha_config: AlphaConfig,
+    mode: BlendMode,
+) {
+    blend_n::<8, BPP>(src, dst, alpha_config, mode)
 }
 
-/// An aligned structure storing `SSAA_SQ` (x, y) subpixel coordinates for up to eight pixels
-pub struct SsaaCoords<const SSAA_SQ: usize> {
-    src_o: [usizex8; SSAA_SQ],
-    src_x: [usizex8; SSAA_SQ],
-    src_y: [usizex8; SSAA_SQ],
+/// An aligned structure storing `SSAA_SQ` (x, y) subpixel coordinates for up to `N` pixels
+pub struct SsaaCoords<const SSAA_SQ: usize, const N: usize = 8>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    src_o: [Simd<usize, N>; SSAA_SQ],
+    src_x: [Simd<usize, N>; SSAA_SQ],
+    src_y: [Simd<usize, N>; SSAA_SQ],
 }
 
-impl<const SSAA_SQ: usize> SsaaCoords<SSAA_SQ> {
+impl<const SSAA_SQ: usize, const N: usize> SsaaCoords<SSAA_SQ, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
     pub fn new() -> Self {
-        const FULL_USIZE_MAX: usizex8 = usizex8::from_array([usize::MAX; 8]);
+        let full_usize_max = Simd::splat(usize::MAX);
         Self {
-            src_o: [FULL_USIZE_MAX; SSAA_SQ],
-            src_x: [FULL_USIZE_MAX; SSAA_SQ],
-            src_y: [FULL_USIZE_MAX; SSAA_SQ],
+            src_o: [full_usize_max; SSAA_SQ],
+            src_x: [full_usize_max; SSAA_SQ],
+            src_y: [full_usize_max; SSAA_SQ],
         }
     }
 
-    /// Insert coordinates (pixel < 8 && sub_pixel < SSAA_SQ)
+    /// Insert coordinates (pixel < N && sub_pixel < SSAA_SQ)
     #[inline(always)]
     pub fn set(&mut self, pixel: usize, sub_pixel: usize, x: usize, y: usize) {
-        assert!(pixel < 8);
+        assert!(pixel < N);
         self.src_o[sub_pixel][pixel] = pixel;
         self.src_x[sub_pixel][pixel] = x;
         self.src_y[sub_pixel][pixel] = y;
     }
 }
 
-/// Performs SSAA on up to 8 pixels
+/// Performs SSAA on up to `N` pixels.
+///
+/// This is the fast integer path; activate the `precise` feature for a
+/// gamma-correct variant that averages subpixels in linear light.
+#[cfg(not(feature = "precise"))]
 #[inline(always)]
-pub fn ssaa8<P: PixelArray, const SSAA_SQ: usize>(
-    src_coords: SsaaCoords<SSAA_SQ>,
+pub fn ssaa_n<P: PixelArray, const SSAA_SQ: usize, const N: usize>(
+    src_coords: SsaaCoords<SSAA_SQ, N>,
     src: &P,
-) -> EightPixels {
-    let src_w = usizex8::from_array([src.width(); 8]);
-    let src_h = usizex8::from_array([src.height(); 8]);
-    let src_l = usizex8::from_array([src.length(); 8]);
+) -> PixelPack<N>
+where
+    LaneCount<N>: SupportedLaneCount,
+    LaneCount<{ N * 4 }>: SupportedLaneCount,
+{
+    let src_w = Simd::splat(src.width());
+    let src_h = Simd::splat(src.height());
+    let src_l = Simd::splat(src.length());
 
     // SUM SUBPIXELS
 
-    let mut ssaa_px = [0; 8];
-    let mut src_sum = [u16x4::from_array([0; 4]); 8];
+    let mut ssaa_px = [0; N];
+    let mut src_sum = [Simd::<u16, 4>::splat(0); N];
 
     for i in 0..SSAA_SQ {
         let src_o = src_coords.src_o[i];
@@ -136,7 +693,7 @@ pub fn ssaa8<P: PixelArray, const SSAA_SQ: usize>(
         let usable_l = src_i.simd_lt(src_l);
         let usable = (usable_x & usable_y & usable_l).to_array();
 
-        for j in 0..8 {
+        for j in 0..N {
             if usable[j] {
                 let rgba = src.get(src_i[j]).into();
                 src_sum[src_o[j]] += u8x4::from_array(rgba).cast();
@@ -147,10 +704,10 @@ pub fn ssaa8<P: PixelArray, const SSAA_SQ: usize>(
 
     // DIVIDE BY NUMBER OF SUBPIXELS
 
-    let mut result = u16x32::from_array([0; 32]);
-    for i in 0..8 {
+    let mut result = [0u16; N * 4];
+    for i in 0..N {
         let j = i * 4;
-        let result = &mut result.as_mut_array()[j..][..4];
+        let result = &mut result[j..][..4];
         let src_sum = src_sum[i].to_array();
 
         let src = if true {
@@ -167,5 +724,67 @@ pub fn ssaa8<P: PixelArray, const SSAA_SQ: usize>(
         result.copy_from_slice(&src);
     }
 
-    EightPixels(result)
+    PixelPack(Simd::from_array(result))
+}
+
+/// Performs SSAA on up to `N` pixels, accumulating subpixel samples as
+/// linearized `f32` lanes (sRGB decode on read, sRGB encode on write) instead
+/// of clipping `u16` sums in gamma-encoded space. This avoids overflow on
+/// wide `SSAA_SQ` factors and composites antialiased edges in the correct
+/// (linear) light, matching [`blend_n`]'s `precise` path.
+#[cfg(feature = "precise")]
+#[inline(always)]
+pub fn ssaa_n<P: PixelArray, const SSAA_SQ: usize, const N: usize>(
+    src_coords: SsaaCoords<SSAA_SQ, N>,
+    src: &P,
+) -> PixelPack<N>
+where
+    LaneCount<N>: SupportedLaneCount,
+    LaneCount<{ N * 4 }>: SupportedLaneCount,
+{
+    let src_w = Simd::splat(src.width());
+    let src_h = Simd::splat(src.height());
+    let src_l = Simd::splat(src.length());
+
+    let mut src_sum = [Simd::<f32, 4>::splat(0.0); N];
+
+    for i in 0..SSAA_SQ {
+        let src_o = src_coords.src_o[i];
+        let src_x = src_coords.src_x[i];
+        let src_y = src_coords.src_y[i];
+        let src_i = src_y * src_w + src_x;
+
+        let usable_x = src_x.simd_lt(src_w);
+        let usable_y = src_y.simd_lt(src_h);
+        let usable_l = src_i.simd_lt(src_l);
+        let usable = (usable_x & usable_y & usable_l).to_array();
+
+        for j in 0..N {
+            if usable[j] {
+                let rgba: [u8; 4] = src.get(src_i[j]).into();
+                let decoded: [f32; 4] = core::array::from_fn(|c| decode_channel(rgba[c] as u16, c == 3));
+                src_sum[src_o[j]] += Simd::from_array(decoded);
+            }
+        }
+    }
+
+    let mut result = [0u16; N * 4];
+    for i in 0..N {
+        let avg = (src_sum[i] / Simd::splat(SSAA_SQ as f32)).to_array();
+        for c in 0..4 {
+            result[i * 4 + c] = encode_channel(avg[c], c == 3);
+        }
+    }
+
+    PixelPack(Simd::from_array(result))
+}
+
+/// Performs SSAA on up to 8 pixels; a thin `N = 8` alias over [`ssaa_n`]
+/// kept for source compatibility.
+#[inline(always)]
+pub fn ssaa8<P: PixelArray, const SSAA_SQ: usize>(
+    src_coords: SsaaCoords<SSAA_SQ>,
+    src: &P,
+) -> EightPixels {
+    ssaa_n(src_coords, src)
 }
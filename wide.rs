@@ -0,0 +1,1002 @@
+#[cfg(not(feature = "precise"))]
+use rgb::{RGBA, FromSlice};
+use super::PixelArray;
+
+// `Lanes8` is a small, stable-Rust stand-in for `core::simd::u16x8`: one
+// 8-lane 16-bit-per-channel vector (two pixels' worth of RGBA16), backed by
+// whatever the target actually offers. Each pack of 8 pixels is processed as
+// four `Lanes8` batches instead of one wide register.
+mod lanes {
+    #[derive(Copy, Clone)]
+    pub struct Lanes8(Repr);
+
+    #[cfg(target_feature = "sse2")]
+    mod repr {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::*;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::*;
+
+        #[derive(Copy, Clone)]
+        pub struct Repr(pub(super) __m128i);
+
+        impl Repr {
+            #[inline(always)]
+            pub fn splat(v: u16) -> Self {
+                Repr(unsafe { _mm_set1_epi16(v as i16) })
+            }
+
+            #[inline(always)]
+            pub fn from_array(a: [u16; 8]) -> Self {
+                Repr(unsafe { _mm_loadu_si128(a.as_ptr() as *const __m128i) })
+            }
+
+            #[inline(always)]
+            pub fn to_array(self) -> [u16; 8] {
+                let mut out = [0u16; 8];
+                unsafe { _mm_storeu_si128(out.as_mut_ptr() as *mut __m128i, self.0) };
+                out
+            }
+
+            #[inline(always)]
+            pub fn add(self, other: Self) -> Self {
+                Repr(unsafe { _mm_add_epi16(self.0, other.0) })
+            }
+
+            #[inline(always)]
+            pub fn sub(self, other: Self) -> Self {
+                Repr(unsafe { _mm_sub_epi16(self.0, other.0) })
+            }
+
+            #[inline(always)]
+            pub fn mul(self, other: Self) -> Self {
+                Repr(unsafe { _mm_mullo_epi16(self.0, other.0) })
+            }
+
+            #[inline(always)]
+            pub fn shr8(self) -> Self {
+                Repr(unsafe { _mm_srli_epi16(self.0, 8) })
+            }
+
+            // SSE2 only has signed 16-bit min/max/compare; flipping each lane's
+            // sign bit maps unsigned order onto signed order, so lanes above
+            // `i16::MAX` (e.g. `div` quotients) still compare as `u16`
+            #[inline(always)]
+            fn bias(v: __m128i) -> __m128i {
+                unsafe { _mm_xor_si128(v, _mm_set1_epi16(i16::MIN)) }
+            }
+
+            #[inline(always)]
+            pub fn min(self, other: Self) -> Self {
+                Repr(bias(unsafe { _mm_min_epi16(bias(self.0), bias(other.0)) }))
+            }
+
+            #[inline(always)]
+            pub fn max(self, other: Self) -> Self {
+                Repr(bias(unsafe { _mm_max_epi16(bias(self.0), bias(other.0)) }))
+            }
+
+            #[inline(always)]
+            pub fn lt(self, other: Self) -> Self {
+                Repr(unsafe { _mm_cmplt_epi16(bias(self.0), bias(other.0)) })
+            }
+
+            #[inline(always)]
+            pub fn eq(self, other: Self) -> Self {
+                Repr(unsafe { _mm_cmpeq_epi16(self.0, other.0) })
+            }
+
+            #[inline(always)]
+            pub fn select(mask: Self, a: Self, b: Self) -> Self {
+                let hit = unsafe { _mm_and_si128(mask.0, a.0) };
+                let miss = unsafe { _mm_andnot_si128(mask.0, b.0) };
+                Repr(unsafe { _mm_or_si128(hit, miss) })
+            }
+        }
+    }
+
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    mod repr {
+        use core::arch::wasm32::*;
+
+        #[derive(Copy, Clone)]
+        pub struct Repr(pub(super) v128);
+
+        impl Repr {
+            #[inline(always)]
+            pub fn splat(v: u16) -> Self {
+                Repr(u16x8_splat(v))
+            }
+
+            #[inline(always)]
+            pub fn from_array(a: [u16; 8]) -> Self {
+                Repr(unsafe { v128_load(a.as_ptr() as *const v128) })
+            }
+
+            #[inline(always)]
+            pub fn to_array(self) -> [u16; 8] {
+                let mut out = [0u16; 8];
+                unsafe { v128_store(out.as_mut_ptr() as *mut v128, self.0) };
+                out
+            }
+
+            #[inline(always)]
+            pub fn add(self, other: Self) -> Self {
+                Repr(u16x8_add(self.0, other.0))
+            }
+
+            #[inline(always)]
+            pub fn sub(self, other: Self) -> Self {
+                Repr(u16x8_sub(self.0, other.0))
+            }
+
+            #[inline(always)]
+            pub fn mul(self, other: Self) -> Self {
+                Repr(u16x8_mul(self.0, other.0))
+            }
+
+            #[inline(always)]
+            pub fn shr8(self) -> Self {
+                Repr(u16x8_shr(self.0, 8))
+            }
+
+            #[inline(always)]
+            pub fn min(self, other: Self) -> Self {
+                Repr(u16x8_min(self.0, other.0))
+            }
+
+            #[inline(always)]
+            pub fn max(self, other: Self) -> Self {
+                Repr(u16x8_max(self.0, other.0))
+            }
+
+            #[inline(always)]
+            pub fn lt(self, other: Self) -> Self {
+                Repr(u16x8_lt(self.0, other.0))
+            }
+
+            #[inline(always)]
+            pub fn eq(self, other: Self) -> Self {
+                Repr(u16x8_eq(self.0, other.0))
+            }
+
+            #[inline(always)]
+            pub fn select(mask: Self, a: Self, b: Self) -> Self {
+                Repr(v128_bitselect(a.0, b.0, mask.0))
+            }
+        }
+    }
+
+    #[cfg(not(any(
+        target_feature = "sse2",
+        all(target_arch = "wasm32", target_feature = "simd128"),
+    )))]
+    mod repr {
+        #[derive(Copy, Clone)]
+        pub struct Repr(pub(super) [u16; 8]);
+
+        impl Repr {
+            #[inline(always)]
+            pub fn splat(v: u16) -> Self {
+                Repr([v; 8])
+            }
+
+            #[inline(always)]
+            pub fn from_array(a: [u16; 8]) -> Self {
+                Repr(a)
+            }
+
+            #[inline(always)]
+            pub fn to_array(self) -> [u16; 8] {
+                self.0
+            }
+
+            #[inline(always)]
+            pub fn add(self, other: Self) -> Self {
+                Repr(core::array::from_fn(|i| self.0[i] + other.0[i]))
+            }
+
+            #[inline(always)]
+            pub fn sub(self, other: Self) -> Self {
+                Repr(core::array::from_fn(|i| self.0[i] - other.0[i]))
+            }
+
+            #[inline(always)]
+            pub fn mul(self, other: Self) -> Self {
+                Repr(core::array::from_fn(|i| self.0[i] * other.0[i]))
+            }
+
+            #[inline(always)]
+            pub fn shr8(self) -> Self {
+                Repr(core::array::from_fn(|i| self.0[i] >> 8))
+            }
+
+            #[inline(always)]
+            pub fn min(self, other: Self) -> Self {
+                Repr(core::array::from_fn(|i| self.0[i].min(other.0[i])))
+            }
+
+            #[inline(always)]
+            pub fn max(self, other: Self) -> Self {
+                Repr(core::array::from_fn(|i| self.0[i].max(other.0[i])))
+            }
+
+            #[inline(always)]
+            pub fn lt(self, other: Self) -> Self {
+                Repr(core::array::from_fn(|i| if self.0[i] < other.0[i] { u16::MAX } else { 0 }))
+            }
+
+            #[inline(always)]
+            pub fn eq(self, other: Self) -> Self {
+                Repr(core::array::from_fn(|i| if self.0[i] == other.0[i] { u16::MAX } else { 0 }))
+            }
+
+            #[inline(always)]
+            pub fn select(mask: Self, a: Self, b: Self) -> Self {
+                Repr(core::array::from_fn(|i| if mask.0[i] != 0 { a.0[i] } else { b.0[i] }))
+            }
+        }
+    }
+
+    use repr::Repr;
+
+    impl Lanes8 {
+        #[inline(always)]
+        pub fn splat(v: u16) -> Self {
+            Self(Repr::splat(v))
+        }
+
+        #[inline(always)]
+        pub fn from_array(a: [u16; 8]) -> Self {
+            Self(Repr::from_array(a))
+        }
+
+        #[inline(always)]
+        pub fn to_array(self) -> [u16; 8] {
+            self.0.to_array()
+        }
+
+        #[inline(always)]
+        pub fn min(self, other: Self) -> Self {
+            Self(self.0.min(other.0))
+        }
+
+        #[inline(always)]
+        pub fn max(self, other: Self) -> Self {
+            Self(self.0.max(other.0))
+        }
+
+        #[inline(always)]
+        pub fn lt(self, other: Self) -> Self {
+            Self(self.0.lt(other.0))
+        }
+
+        #[inline(always)]
+        pub fn eq(self, other: Self) -> Self {
+            Self(self.0.eq(other.0))
+        }
+
+        #[inline(always)]
+        pub fn select(mask: Self, a: Self, b: Self) -> Self {
+            Self(Repr::select(mask.0, a.0, b.0))
+        }
+
+        /// Correctly-rounded `round(x / 255)`, without an actual division
+        #[inline(always)]
+        pub fn div255(self) -> Self {
+            let t = self + Self::splat(128);
+            (t.shr8() + t).shr8()
+        }
+
+        #[inline(always)]
+        fn shr8(self) -> Self {
+            Self(self.0.shr8())
+        }
+
+        /// There is no portable integer-divide instruction on SSE2/`simd128`;
+        /// this lane-by-lane fallback is only used by `ColorDodge`/`ColorBurn`
+        /// and by un-premultiplying (see `unpremultiply_colors`)
+        #[inline(always)]
+        pub fn div(self, other: Self) -> Self {
+            let a = self.to_array();
+            let b = other.to_array();
+            Self::from_array(core::array::from_fn(|i| a[i] / b[i].max(1)))
+        }
+    }
+
+    impl core::ops::Add for Lanes8 {
+        type Output = Self;
+        #[inline(always)]
+        fn add(self, other: Self) -> Self { Self(self.0.add(other.0)) }
+    }
+
+    impl core::ops::Sub for Lanes8 {
+        type Output = Self;
+        #[inline(always)]
+        fn sub(self, other: Self) -> Self { Self(self.0.sub(other.0)) }
+    }
+
+    impl core::ops::Mul for Lanes8 {
+        type Output = Self;
+        #[inline(always)]
+        fn mul(self, other: Self) -> Self { Self(self.0.mul(other.0)) }
+    }
+}
+
+use lanes::Lanes8;
+use core::ops::Mul;
+
+/// sRGB (0..=255) to linear light, used by the `precise` feature's gamma-correct path
+#[cfg(feature = "precise")]
+fn srgb_to_linear(c: u16) -> f32 {
+    let c = c as f32 / 255.0;
+    // `f32::powf` isn't available in `core`; this crate is `#![no_std]`
+    if c <= 0.04045 { c / 12.92 } else { libm::powf((c + 0.055) / 1.055, 2.4) }
+}
+
+/// Linear light to sRGB (0..=255)
+#[cfg(feature = "precise")]
+fn linear_to_srgb(c: f32) -> u16 {
+    let c = c.clamp(0.0, 1.0);
+    let encoded = if c <= 0.0031308 { c * 12.92 } else { 1.055 * libm::powf(c, 1.0 / 2.4) - 0.055 };
+    (encoded * 255.0 + 0.5) as u16
+}
+
+/// Decode one canonical channel to a linearized `0.0..=1.0` float; alpha is
+/// already linear and is only rescaled, never gamma-decoded
+#[cfg(feature = "precise")]
+fn decode_channel(value: u16, is_alpha: bool) -> f32 {
+    match is_alpha {
+        true => value as f32 / 255.0,
+        false => srgb_to_linear(value),
+    }
+}
+
+/// Encode one linearized `0.0..=1.0` float back to a canonical channel
+#[cfg(feature = "precise")]
+fn encode_channel(value: f32, is_alpha: bool) -> u16 {
+    match is_alpha {
+        true => (value.clamp(0.0, 1.0) * 255.0 + 0.5) as u16,
+        false => linear_to_srgb(value),
+    }
+}
+
+/// A pack of 8 pixels (SIMD alignment)
+#[derive(Copy, Clone, Debug)]
+#[repr(transparent)]
+pub struct EightPixels([u16; 32]);
+
+impl EightPixels {
+    /// Read up to 8 pixels from a byte slice, `BPP` bytes per pixel, unpacking
+    /// into the canonical 4-channel RGBA16 working form
+    pub fn new<const BPP: usize>(src: &[u8]) -> Self {
+        if BPP == 4 {
+            let mut array = [0; 32];
+            array[..src.len()].copy_from_slice(src);
+            return Self(array.map(|item| item as u16));
+        }
+
+        let mut lanes = [0u16; 32];
+        for (p, bytes) in src.chunks_exact(BPP).take(8).enumerate() {
+            lanes[p * 4..][..4].copy_from_slice(&unpack::<BPP>(bytes));
+        }
+        Self(lanes)
+    }
+
+    /// Write up to 8 pixels to a byte slice, `BPP` bytes per pixel, repacking
+    /// from the canonical 4-channel RGBA16 working form
+    pub fn write<const BPP: usize>(&self, dst: &mut [u8]) {
+        if BPP == 4 {
+            dst.copy_from_slice(&self.0.map(|item| item as u8)[..dst.len()]);
+            return;
+        }
+
+        for (p, bytes) in dst.chunks_exact_mut(BPP).take(8).enumerate() {
+            let rgba = [self.0[p * 4], self.0[p * 4 + 1], self.0[p * 4 + 2], self.0[p * 4 + 3]];
+            bytes.copy_from_slice(&pack::<BPP>(rgba)[..BPP]);
+        }
+    }
+
+    /// Multiply color channels by their pixel's alpha (`Cx' = Cx·α/255`);
+    /// the alpha channel itself passes through unmodified
+    pub fn premultiply(&self, alpha_config: AlphaConfig) -> Self {
+        match alpha_config.channel() {
+            Some(channel) => {
+                let mut result = self.0;
+                for_each_batch(&mut result, self.0, |item, alpha| premultiply_colors(item, alpha, channel), channel);
+                Self(result)
+            }
+            None => *self,
+        }
+    }
+
+    /// Undo premultiplication (`Cx = min(255, Cx'·255/α)`); the alpha
+    /// channel itself passes through unmodified
+    pub fn unpremultiply(&self, alpha_config: AlphaConfig) -> Self {
+        match alpha_config.channel() {
+            Some(channel) => {
+                let mut result = self.0;
+                for_each_batch(&mut result, self.0, |item, _alpha| unpremultiply_colors(item, channel), channel);
+                Self(result)
+            }
+            None => *self,
+        }
+    }
+}
+
+/// Run `f(item_batch, alpha_batch)` over the four 8-lane (two pixel) batches
+/// of a 32-lane pixel pack, writing the result back into `result`
+#[inline(always)]
+fn for_each_batch(
+    result: &mut [u16; 32],
+    src: [u16; 32],
+    f: impl Fn(Lanes8, Lanes8) -> Lanes8,
+    channel: usize,
+) {
+    for batch in 0..4 {
+        let base = batch * 8;
+        let item = Lanes8::from_array(src[base..][..8].try_into().unwrap());
+        let alpha = Lanes8::from_array([
+            src[base + channel], src[base + channel], src[base + channel], src[base + channel],
+            src[base + 4 + channel], src[base + 4 + channel], src[base + 4 + channel], src[base + 4 + channel],
+        ]);
+        result[base..][..8].copy_from_slice(&f(item, alpha).to_array());
+    }
+}
+
+/// Unpack one pixel (`BPP` bytes) into canonical `[r, g, b, a]` 16-bit channels.
+/// Formats without an alpha channel (3, 2 and 6 bytes per pixel) report full opacity.
+fn unpack<const BPP: usize>(bytes: &[u8]) -> [u16; 4] {
+    match BPP {
+        // 24bpp RGB
+        3 => [bytes[0] as u16, bytes[1] as u16, bytes[2] as u16, u8::MAX as u16],
+        // RGB565
+        2 => {
+            let packed = u16::from_le_bytes([bytes[0], bytes[1]]);
+            let r5 = (packed >> 11) & 0x1F;
+            let g6 = (packed >> 5) & 0x3F;
+            let b5 = packed & 0x1F;
+            [(r5 << 3) | (r5 >> 2), (g6 << 2) | (g6 >> 4), (b5 << 3) | (b5 >> 2), u8::MAX as u16]
+        }
+        // 16-bit-per-channel RGB, downscaled to 8-bit
+        6 => [
+            u16::from_le_bytes([bytes[0], bytes[1]]) >> 8,
+            u16::from_le_bytes([bytes[2], bytes[3]]) >> 8,
+            u16::from_le_bytes([bytes[4], bytes[5]]) >> 8,
+            u8::MAX as u16,
+        ],
+        // 16-bit-per-channel RGBA, downscaled to 8-bit
+        8 => [
+            u16::from_le_bytes([bytes[0], bytes[1]]) >> 8,
+            u16::from_le_bytes([bytes[2], bytes[3]]) >> 8,
+            u16::from_le_bytes([bytes[4], bytes[5]]) >> 8,
+            u16::from_le_bytes([bytes[6], bytes[7]]) >> 8,
+        ],
+        // 8-bit RGBA (the `BPP == 4` fast path bypasses this function)
+        _ => [bytes[0] as u16, bytes[1] as u16, bytes[2] as u16, bytes[3] as u16],
+    }
+}
+
+/// Repack canonical `[r, g, b, a]` 16-bit channels into one pixel (`BPP` bytes)
+fn pack<const BPP: usize>(rgba: [u16; 4]) -> [u8; 8] {
+    match BPP {
+        3 => [rgba[0] as u8, rgba[1] as u8, rgba[2] as u8, 0, 0, 0, 0, 0],
+        2 => {
+            let r5 = (rgba[0] >> 3) & 0x1F;
+            let g6 = (rgba[1] >> 2) & 0x3F;
+            let b5 = (rgba[2] >> 3) & 0x1F;
+            let packed = ((r5 << 11) | (g6 << 5) | b5).to_le_bytes();
+            [packed[0], packed[1], 0, 0, 0, 0, 0, 0]
+        }
+        6 => {
+            let [r0, r1] = widen(rgba[0]);
+            let [g0, g1] = widen(rgba[1]);
+            let [b0, b1] = widen(rgba[2]);
+            [r0, r1, g0, g1, b0, b1, 0, 0]
+        }
+        8 => {
+            let [r0, r1] = widen(rgba[0]);
+            let [g0, g1] = widen(rgba[1]);
+            let [b0, b1] = widen(rgba[2]);
+            let [a0, a1] = widen(rgba[3]);
+            [r0, r1, g0, g1, b0, b1, a0, a1]
+        }
+        _ => [rgba[0] as u8, rgba[1] as u8, rgba[2] as u8, rgba[3] as u8, 0, 0, 0, 0],
+    }
+}
+
+/// Replicate an 8-bit channel value into a 16-bit-per-channel byte pair (e.g. `0xab` -> `0xabab`)
+fn widen(channel: u16) -> [u8; 2] {
+    ((channel << 8) | channel).to_le_bytes()
+}
+
+/// Supported Alpha configurations
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[repr(usize)]
+pub enum AlphaConfig {
+    FirstByte,
+    SecondByte,
+    ThirdByte,
+    FourthByte,
+    /// Like `FirstByte`, but source and destination color channels are
+    /// already premultiplied by their pixel's alpha
+    PremultipliedFirstByte,
+    /// Like `SecondByte`, but source and destination color channels are
+    /// already premultiplied by their pixel's alpha
+    PremultipliedSecondByte,
+    /// Like `ThirdByte`, but source and destination color channels are
+    /// already premultiplied by their pixel's alpha
+    PremultipliedThirdByte,
+    /// Like `FourthByte`, but source and destination color channels are
+    /// already premultiplied by their pixel's alpha
+    PremultipliedFourthByte,
+    /// The pixels will be directly copied, no blending
+    None,
+}
+
+impl AlphaConfig {
+    /// Byte offset of the alpha channel within a pixel, if any
+    fn channel(self) -> Option<usize> {
+        match self {
+            AlphaConfig::FirstByte  | AlphaConfig::PremultipliedFirstByte  => Some(0),
+            AlphaConfig::SecondByte | AlphaConfig::PremultipliedSecondByte => Some(1),
+            AlphaConfig::ThirdByte  | AlphaConfig::PremultipliedThirdByte  => Some(2),
+            AlphaConfig::FourthByte | AlphaConfig::PremultipliedFourthByte => Some(3),
+            AlphaConfig::None => None,
+        }
+    }
+
+    /// Whether color channels are already premultiplied by alpha
+    fn is_premultiplied(self) -> bool {
+        matches!(
+            self,
+            AlphaConfig::PremultipliedFirstByte
+                | AlphaConfig::PremultipliedSecondByte
+                | AlphaConfig::PremultipliedThirdByte
+                | AlphaConfig::PremultipliedFourthByte
+        )
+    }
+}
+
+/// Porter-Duff operators and separable blend modes, composited onto the
+/// destination pixel in place of plain source-over copy/blend
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BlendMode {
+    Clear,
+    Src,
+    Dst,
+    SrcOver,
+    DstOver,
+    SrcIn,
+    DstIn,
+    SrcOut,
+    DstOut,
+    SrcAtop,
+    DstAtop,
+    Xor,
+    Add,
+    Multiply,
+    Screen,
+    Overlay,
+    Darken,
+    Lighten,
+    ColorDodge,
+    ColorBurn,
+    HardLight,
+    Difference,
+}
+
+/// Per-channel `(Fa, Fb)` coefficients for `Cr = Fa·Cs + Fb·Cd`, scaled to `0..=255`
+#[inline(always)]
+fn porter_duff_coefficients(mode: BlendMode, src_a: Lanes8, dst_a: Lanes8) -> (Lanes8, Lanes8) {
+    let zero = Lanes8::splat(0);
+    let full = Lanes8::splat(u8::MAX as u16);
+    let inv_src_a = full - src_a;
+    let inv_dst_a = full - dst_a;
+
+    match mode {
+        BlendMode::Clear   => (zero, zero),
+        BlendMode::Src     => (full, zero),
+        BlendMode::Dst     => (zero, full),
+        BlendMode::SrcOver => (full, inv_src_a),
+        BlendMode::DstOver => (inv_dst_a, full),
+        BlendMode::SrcIn   => (dst_a, zero),
+        BlendMode::DstIn   => (zero, src_a),
+        BlendMode::SrcOut  => (inv_dst_a, zero),
+        BlendMode::DstOut  => (zero, inv_src_a),
+        BlendMode::SrcAtop => (dst_a, inv_src_a),
+        BlendMode::DstAtop => (inv_dst_a, src_a),
+        BlendMode::Xor     => (inv_dst_a, inv_src_a),
+        BlendMode::Add     => (full, full),
+        // separable blend modes replace the source channel with the
+        // blended channel (see `separable_blend`) and composite like `SrcOver`
+        _ => (full, inv_src_a),
+    }
+}
+
+/// Per-channel separable blend function `B(Cs, Cd)`, fed into the over equation afterwards
+#[inline(always)]
+fn separable_blend(mode: BlendMode, src: Lanes8, dst: Lanes8) -> Lanes8 {
+    let full = Lanes8::splat(u8::MAX as u16);
+    let zero = Lanes8::splat(0);
+
+    match mode {
+        BlendMode::Multiply => (src * dst).div255(),
+        BlendMode::Screen => src + dst - (src * dst).div255(),
+        BlendMode::Darken => src.min(dst),
+        BlendMode::Lighten => src.max(dst),
+        BlendMode::Difference => src.max(dst) - src.min(dst),
+        // hard light swaps which operand drives the branch, compared to overlay
+        BlendMode::Overlay => hard_light(dst, src),
+        BlendMode::HardLight => hard_light(src, dst),
+        BlendMode::ColorDodge => {
+            // `div` guards against a zero divisor; the `src < full` select
+            // below handles the true `src >= full` case instead
+            let dodged = (dst * full).div(full - src).min(full);
+            Lanes8::select(src.lt(full), dodged, full)
+        }
+        BlendMode::ColorBurn => {
+            let burned = full - ((full - dst) * full).div(src).min(full);
+            Lanes8::select(src.eq(zero), zero, burned)
+        }
+        _ => src,
+    }
+}
+
+#[inline(always)]
+fn hard_light(src: Lanes8, dst: Lanes8) -> Lanes8 {
+    let full = Lanes8::splat(u8::MAX as u16);
+    let two = Lanes8::splat(2);
+    let half = Lanes8::splat(128);
+
+    let lo = (src * dst * two).div255();
+    let hi = full - ((full - src) * (full - dst) * two).div255();
+    Lanes8::select(src.lt(half), lo, hi)
+}
+
+/// Broadcast each pixel's byte at `channel` (0..=3) across that pixel's 4 lanes
+/// of an 8-lane (2-pixel) batch, e.g. for `channel == 3`: `[a0,a0,a0,a0, a1,a1,a1,a1]`
+#[inline(always)]
+fn gather_alpha(batch: Lanes8, channel: usize) -> Lanes8 {
+    let a = batch.to_array();
+    Lanes8::from_array([
+        a[channel], a[channel], a[channel], a[channel],
+        a[4 + channel], a[4 + channel], a[4 + channel], a[4 + channel],
+    ])
+}
+
+/// Mask that is non-zero at each pixel's alpha lane (position `channel` within
+/// every 4-lane pixel) and zero elsewhere; used with [`Lanes8::select`] to leave
+/// the alpha channel untouched while un-premultiplying color channels.
+#[inline(always)]
+fn alpha_lane_mask(channel: usize) -> Lanes8 {
+    let lane = |i: usize| if i % 4 == channel { u16::MAX } else { 0 };
+    Lanes8::from_array([lane(0), lane(1), lane(2), lane(3), lane(4), lane(5), lane(6), lane(7)])
+}
+
+/// Multiply color lanes by `alpha`, leaving the alpha lane itself untouched
+/// (premultiplied alpha is defined as `Cx' = Cx·α/255, α' = α`; multiplying
+/// alpha by itself would corrupt it).
+#[inline(always)]
+fn premultiply_colors(item: Lanes8, alpha: Lanes8, channel: usize) -> Lanes8 {
+    let straight = item.mul(alpha).div255();
+    Lanes8::select(alpha_lane_mask(channel), item, straight)
+}
+
+/// Divide color lanes by the freshly composited alpha, undoing the premultiply
+/// applied before blending; the alpha lane itself is left untouched. Porter-Duff
+/// math is only valid on premultiplied channels, so straight `AlphaConfig`
+/// callers need this correction before the result is written back.
+#[inline(always)]
+fn unpremultiply_colors(combined: Lanes8, channel: usize) -> Lanes8 {
+    let full = Lanes8::splat(u8::MAX as u16);
+    let one = Lanes8::splat(1);
+    let alpha_r = gather_alpha(combined, channel).max(one);
+    let straight = combined.mul(full).div(alpha_r).min(full);
+    Lanes8::select(alpha_lane_mask(channel), combined, straight)
+}
+
+/// Perform alpha compositing on up to eight pixels using the given blend mode.
+/// `BPP` is the byte stride of `dst` (and of the bytes `src` was built from).
+///
+/// If `alpha_config` is one of the `Premultiplied*` variants, both `src` and
+/// the current content of `dst` are expected to hold color channels already
+/// multiplied by their pixel's alpha (see [`EightPixels::premultiply`]).
+///
+/// This is the fast integer path; activate the `precise` feature for a
+/// gamma-correct variant that composites in linear light.
+#[cfg(not(feature = "precise"))]
+#[inline(always)]
+pub fn blend8<const BPP: usize>(
+    src: EightPixels,
+    dst: &mut [u8],
+    alpha_config: AlphaConfig,
+    mode: BlendMode,
+) {
+    let result = if let Some(channel) = alpha_config.channel() {
+        let dst_p = EightPixels::new::<BPP>(dst);
+        let premultiplied = alpha_config.is_premultiplied();
+
+        let is_separable = matches!(
+            mode,
+            BlendMode::Multiply | BlendMode::Screen | BlendMode::Overlay
+                | BlendMode::Darken | BlendMode::Lighten | BlendMode::ColorDodge
+                | BlendMode::ColorBurn | BlendMode::HardLight | BlendMode::Difference
+        );
+
+        let is_add = matches!(mode, BlendMode::Add);
+
+        let mut out = [0u16; 32];
+        for batch in 0..4 {
+            let base = batch * 8;
+            let src_b = Lanes8::from_array(src.0[base..][..8].try_into().unwrap());
+            let dst_b = Lanes8::from_array(dst_p.0[base..][..8].try_into().unwrap());
+            let src_a = gather_alpha(src_b, channel);
+            let dst_a = gather_alpha(dst_b, channel);
+
+            let (src_m, dst_m) = if premultiplied {
+                (src_b, dst_b)
+            } else {
+                (premultiply_colors(src_b, src_a, channel), premultiply_colors(dst_b, dst_a, channel))
+            };
+
+            // Separable blend functions operate on color only; the alpha
+            // channel always goes through the plain over equation below.
+            let effective_src = match is_separable {
+                true => Lanes8::select(alpha_lane_mask(channel), src_m, separable_blend(mode, src_m, dst_m)),
+                false => src_m,
+            };
+
+            let full = Lanes8::splat(u8::MAX as u16);
+
+            // `Add`'s (Fa, Fb) coefficients are both `full`, i.e. a no-op scale;
+            // going through the usual multiply would overflow a `u16` for two
+            // bright pixels, so treat it as a plain saturating sum instead.
+            let combined = if is_add {
+                effective_src + dst_m
+            } else {
+                let (fa, fb) = porter_duff_coefficients(mode, src_a, dst_a);
+                ((effective_src * fa) + (dst_m * fb)).div255()
+            };
+            let combined = combined.min(full);
+
+            // Porter-Duff math is only valid on premultiplied channels; when
+            // the caller passed a straight `AlphaConfig`, undo the premultiply
+            // we applied above before handing the result back.
+            let combined = match premultiplied {
+                true => combined,
+                false => unpremultiply_colors(combined, channel),
+            };
+
+            out[base..][..8].copy_from_slice(&combined.to_array());
+        }
+
+        EightPixels(out)
+    } else {
+        src
+    };
+
+    result.write::<BPP>(dst);
+}
+
+/// Perform alpha compositing on up to eight pixels using the given blend mode,
+/// performing the over equation in linearized light (gamma-correct).
+/// `BPP` is the byte stride of `dst` (and of the bytes `src` was built from).
+///
+/// Blend-mode-specific functions (`Multiply`, `Screen`, ...) still operate on
+/// gamma-encoded values, matching the CSS/SVG compositing spec; only the
+/// final `Fa·Cs + Fb·Cd` combination happens in linear light.
+///
+/// If `alpha_config` is one of the `Premultiplied*` variants, both `src` and
+/// the current content of `dst` are expected to hold color channels already
+/// multiplied by their pixel's alpha (see [`EightPixels::premultiply`]).
+#[cfg(feature = "precise")]
+#[inline(always)]
+pub fn blend8<const BPP: usize>(
+    src: EightPixels,
+    dst: &mut [u8],
+    alpha_config: AlphaConfig,
+    mode: BlendMode,
+) {
+    let result = if let Some(channel) = alpha_config.channel() {
+        let dst_p = EightPixels::new::<BPP>(dst);
+        let premultiplied = alpha_config.is_premultiplied();
+
+        let is_separable = matches!(
+            mode,
+            BlendMode::Multiply | BlendMode::Screen | BlendMode::Overlay
+                | BlendMode::Darken | BlendMode::Lighten | BlendMode::ColorDodge
+                | BlendMode::ColorBurn | BlendMode::HardLight | BlendMode::Difference
+        );
+
+        let mut out = [0u16; 32];
+        for batch in 0..4 {
+            let base = batch * 8;
+            let src_b = Lanes8::from_array(src.0[base..][..8].try_into().unwrap());
+            let dst_b = Lanes8::from_array(dst_p.0[base..][..8].try_into().unwrap());
+            let src_a = gather_alpha(src_b, channel);
+            let dst_a = gather_alpha(dst_b, channel);
+
+            let (src_m, dst_m) = if premultiplied {
+                (src_b, dst_b)
+            } else {
+                (premultiply_colors(src_b, src_a, channel), premultiply_colors(dst_b, dst_a, channel))
+            };
+
+            // Separable blend functions operate on color only; the alpha
+            // channel always goes through the plain over equation below.
+            let effective_src = match is_separable {
+                true => Lanes8::select(alpha_lane_mask(channel), src_m, separable_blend(mode, src_m, dst_m)),
+                false => src_m,
+            };
+
+            let (fa, fb) = porter_duff_coefficients(mode, src_a, dst_a);
+
+            let src_arr = effective_src.to_array();
+            let dst_arr = dst_m.to_array();
+            let fa_arr = fa.to_array();
+            let fb_arr = fb.to_array();
+
+            let mut combined = [0u16; 8];
+            for lane in 0..8 {
+                let is_alpha = (base + lane) % 4 == channel;
+                let value = decode_channel(src_arr[lane], is_alpha) * (fa_arr[lane] as f32 / 255.0)
+                    + decode_channel(dst_arr[lane], is_alpha) * (fb_arr[lane] as f32 / 255.0);
+                combined[lane] = encode_channel(value, is_alpha);
+            }
+
+            // Porter-Duff math is only valid on premultiplied channels; when
+            // the caller passed a straight `AlphaConfig`, undo the premultiply
+            // we applied above before handing the result back.
+            if !premultiplied {
+                let full = u8::MAX as u16;
+                for lane in 0..8 {
+                    if lane % 4 != channel {
+                        let alpha_r = combined[(lane - lane % 4) + channel].max(1);
+                        combined[lane] = (combined[lane] * full / alpha_r).min(full);
+                    }
+                }
+            }
+
+            out[base..][..8].copy_from_slice(&combined);
+        }
+
+        EightPixels(out)
+    } else {
+        src
+    };
+
+    result.write::<BPP>(dst);
+}
+
+/// An aligned structure storing `SSAA_SQ` (x, y) subpixel coordinates for up to eight pixels
+pub struct SsaaCoords<const SSAA_SQ: usize> {
+    src_o: [[usize; 8]; SSAA_SQ],
+    src_x: [[usize; 8]; SSAA_SQ],
+    src_y: [[usize; 8]; SSAA_SQ],
+}
+
+impl<const SSAA_SQ: usize> SsaaCoords<SSAA_SQ> {
+    pub fn new() -> Self {
+        const FULL_USIZE_MAX: [usize; 8] = [usize::MAX; 8];
+        Self {
+            src_o: [FULL_USIZE_MAX; SSAA_SQ],
+            src_x: [FULL_USIZE_MAX; SSAA_SQ],
+            src_y: [FULL_USIZE_MAX; SSAA_SQ],
+        }
+    }
+
+    /// Insert coordinates (pixel < 8 && sub_pixel < SSAA_SQ)
+    #[inline(always)]
+    pub fn set(&mut self, pixel: usize, sub_pixel: usize, x: usize, y: usize) {
+        assert!(pixel < 8);
+        self.src_o[sub_pixel][pixel] = pixel;
+        self.src_x[sub_pixel][pixel] = x;
+        self.src_y[sub_pixel][pixel] = y;
+    }
+}
+
+/// Performs SSAA on up to 8 pixels.
+///
+/// This is the fast integer path; activate the `precise` feature for a
+/// gamma-correct variant that averages subpixels in linear light.
+#[cfg(not(feature = "precise"))]
+#[inline(always)]
+pub fn ssaa8<P: PixelArray, const SSAA_SQ: usize>(
+    src_coords: SsaaCoords<SSAA_SQ>,
+    src: &P,
+) -> EightPixels {
+    let src_w = src.width();
+    let src_h = src.height();
+    let src_l = src.length();
+
+    // SUM SUBPIXELS
+
+    let mut ssaa_px = [0; 8];
+    let mut result = EightPixels::new::<4>(&[]);
+
+    for i in 0..SSAA_SQ {
+        for j in 0..8 {
+            let src_o = src_coords.src_o[i][j];
+            let src_x = src_coords.src_x[i][j];
+            let src_y = src_coords.src_y[i][j];
+            let src_i = src_y * src_w + src_x;
+
+            let usable_x = src_x < src_w;
+            let usable_y = src_y < src_h;
+            let usable_l = src_i < src_l;
+            let usable = usable_x & usable_y & usable_l;
+
+            if usable {
+                let rgba: RGBA<u16> = src.get(src_i).into();
+                result.0.as_rgba_mut()[src_o] += rgba;
+                ssaa_px[src_o] += 1;
+            }
+        }
+    }
+
+    // DIVIDE BY NUMBER OF SUBPIXELS
+
+    for i in 0..8 {
+        result.0.as_rgba_mut()[i] /= if true {
+            // better perf but some weird line SouthEast
+            SSAA_SQ as u16
+        } else {
+            match ssaa_px[i] {
+                0 => 1,
+                n => n,
+            }
+        };
+    }
+
+    result
+}
+
+/// Performs SSAA on up to 8 pixels, accumulating subpixel samples as
+/// linearized `f32` lanes (sRGB decode on read, sRGB encode on write) instead
+/// of clipping `u16` sums in gamma-encoded space. This avoids overflow on
+/// wide `SSAA_SQ` factors and composites antialiased edges in the correct
+/// (linear) light, matching [`blend8`]'s `precise` path.
+#[cfg(feature = "precise")]
+#[inline(always)]
+pub fn ssaa8<P: PixelArray, const SSAA_SQ: usize>(
+    src_coords: SsaaCoords<SSAA_SQ>,
+    src: &P,
+) -> EightPixels {
+    let src_w = src.width();
+    let src_h = src.height();
+    let src_l = src.length();
+
+    let mut sums = [0f32; 32];
+
+    for i in 0..SSAA_SQ {
+        for j in 0..8 {
+            let src_o = src_coords.src_o[i][j];
+            let src_x = src_coords.src_x[i][j];
+            let src_y = src_coords.src_y[i][j];
+            let src_i = src_y * src_w + src_x;
+
+            let usable_x = src_x < src_w;
+            let usable_y = src_y < src_h;
+            let usable_l = src_i < src_l;
+            let usable = usable_x & usable_y & usable_l;
+
+            if usable {
+                let rgba: [u8; 4] = src.get(src_i).into();
+                for c in 0..4 {
+                    sums[src_o * 4 + c] += decode_channel(rgba[c] as u16, c == 3);
+                }
+            }
+        }
+    }
+
+    let mut result = [0u16; 32];
+    for i in 0..8 {
+        for c in 0..4 {
+            let average = sums[i * 4 + c] / SSAA_SQ as f32;
+            result[i * 4 + c] = encode_channel(average, c == 3);
+        }
+    }
+
+    EightPixels(result)
+}